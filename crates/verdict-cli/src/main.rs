@@ -0,0 +1,189 @@
+//! `verdict` — validate a CSV file against a schema and a rule suite.
+//!
+//! Exit codes: 0 when no error-severity rule failed (warnings are allowed),
+//! 1 when at least one did, 2 when the inputs could not be loaded.
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use thiserror::Error;
+use verdict_core::{
+    csv_loader::{CsvLoadingError, CsvOptions, DatasetCsvExt, validate_csv},
+    dataset::Dataset,
+    errors::ValidationError,
+    report::Report,
+    rules::{CompiledRules, ValidationOptions, Verdict, check_rules, validate_compiled},
+    suite::{SuiteError, load_rules, load_schema},
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "verdict",
+    version,
+    about = "Validate a CSV file against a rule suite"
+)]
+struct Cli {
+    /// CSV file to validate.
+    data: PathBuf,
+
+    /// Schema file (.json, .yaml, .yml or .toml) describing the CSV columns.
+    #[arg(short, long)]
+    schema: PathBuf,
+
+    /// Rule suite file (.json, .yaml, .yml or .toml).
+    #[arg(short, long)]
+    rules: PathBuf,
+
+    /// Also write the report as JSON to this file.
+    #[arg(long, value_name = "FILE")]
+    json: Option<PathBuf>,
+
+    /// Also write the report as JUnit XML to this file.
+    #[arg(long, value_name = "FILE")]
+    junit: Option<PathBuf>,
+
+    /// Also write the report as a Markdown summary to this file.
+    #[arg(long, value_name = "FILE")]
+    markdown: Option<PathBuf>,
+
+    /// Maximum number of failing row indices recorded per rule.
+    #[arg(long, default_value_t = ValidationOptions::default().max_failed_rows)]
+    max_failed_rows: usize,
+
+    /// Maximum number of failing values sampled per rule.
+    #[arg(long, default_value_t = ValidationOptions::default().max_sample_values)]
+    max_sample_values: usize,
+
+    /// Load unparsable cells as nulls and report them as type-conformance failures.
+    #[arg(long)]
+    lenient: bool,
+
+    /// Check the rules against the schema's column types first and stop if
+    /// any rule doesn't apply, instead of reporting it as a failed rule.
+    #[arg(long)]
+    strict: bool,
+
+    /// Validate the file in batches of this many rows instead of loading it whole.
+    #[arg(long, value_name = "ROWS")]
+    batch_size: Option<usize>,
+
+    /// Don't print the report to stdout.
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Debug, Error)]
+enum CliError {
+    #[error("{}: {source}", path.display())]
+    Suite { path: PathBuf, source: SuiteError },
+
+    #[error("{}: {source}", path.display())]
+    Rules {
+        path: PathBuf,
+        source: Box<ValidationError>,
+    },
+
+    #[error("{}: rules don't match the schema:{}", path.display(), bullet_list(errors))]
+    RuleCheck {
+        path: PathBuf,
+        errors: Vec<ValidationError>,
+    },
+
+    #[error("{}: {source}", path.display())]
+    Csv {
+        path: PathBuf,
+        source: CsvLoadingError,
+    },
+
+    #[error("Failed to write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(Verdict::Failed) => ExitCode::from(1),
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<Verdict, CliError> {
+    let suite_error = |path: &PathBuf| {
+        let path = path.clone();
+        move |source| CliError::Suite { path, source }
+    };
+    let schema = load_schema(&cli.schema).map_err(suite_error(&cli.schema))?;
+    let rules = load_rules(&cli.rules).map_err(suite_error(&cli.rules))?;
+    if cli.strict {
+        let errors = check_rules(&schema, &rules);
+        if !errors.is_empty() {
+            return Err(CliError::RuleCheck {
+                path: cli.rules.clone(),
+                errors,
+            });
+        }
+    }
+    let rules = CompiledRules::new(&rules).map_err(|source| CliError::Rules {
+        path: cli.rules.clone(),
+        source: Box::new(source),
+    })?;
+    let csv_options = CsvOptions {
+        lenient: cli.lenient,
+        ..CsvOptions::default()
+    };
+    let options = ValidationOptions {
+        max_failed_rows: cli.max_failed_rows,
+        max_sample_values: cli.max_sample_values,
+    };
+    let data_path = cli.data.to_string_lossy();
+    let report = match cli.batch_size {
+        Some(batch_size) => validate_csv(
+            &data_path,
+            &schema,
+            rules.rules(),
+            &csv_options,
+            &options,
+            batch_size,
+        ),
+        None => Dataset::from_csv_with_options(&data_path, &schema, &csv_options)
+            .map(|data| validate_compiled(&data, &rules, &options)),
+    }
+    .map_err(|source| CliError::Csv {
+        path: cli.data.clone(),
+        source,
+    })?;
+
+    if !cli.quiet {
+        print!("{}", report);
+    }
+    write_output(cli.json.as_ref(), &report, Report::to_json)?;
+    write_output(cli.junit.as_ref(), &report, Report::to_junit)?;
+    write_output(cli.markdown.as_ref(), &report, Report::to_markdown)?;
+
+    Ok(report.verdict())
+}
+
+fn write_output(
+    path: Option<&PathBuf>,
+    report: &Report,
+    render: fn(&Report) -> String,
+) -> Result<(), CliError> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    std::fs::write(path, render(report)).map_err(|source| CliError::Write {
+        path: path.clone(),
+        source,
+    })
+}
+
+fn bullet_list(errors: &[ValidationError]) -> String {
+    errors.iter().map(|e| format!("\n  - {}", e)).collect()
+}
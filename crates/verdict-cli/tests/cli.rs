@@ -0,0 +1,168 @@
+use std::{path::PathBuf, process::Command};
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+fn verdict(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_verdict"))
+        .args(args)
+        .output()
+        .expect("failed to run verdict")
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("verdict-cli-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn test_passing_suite_with_warnings_exits_zero() {
+    let output = verdict(&[
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("passing.yaml"),
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Verdict: PASSED WITH WARNINGS (2 passed, 0 failed, 1 warnings, 0 info)")
+    );
+    assert!(stdout.contains("WARN: column 'age'"));
+}
+
+#[test]
+fn test_failing_suite_exits_one_and_writes_outputs() {
+    let json = temp_path("report.json");
+    let junit = temp_path("report.xml");
+    let markdown = temp_path("report.md");
+    let output = verdict(&[
+        &fixture("people.csv"),
+        "-s",
+        &fixture("schema.yaml"),
+        "-r",
+        &fixture("failing.toml"),
+        "--json",
+        json.to_str().unwrap(),
+        "--junit",
+        junit.to_str().unwrap(),
+        "--markdown",
+        markdown.to_str().unwrap(),
+        "--quiet",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+
+    let json = std::fs::read_to_string(json).unwrap();
+    assert!(json.contains("\"verdict\": \"failed\""));
+    assert!(json.contains("\"failed_rows\": [\n        1,\n        3\n      ]"));
+    let junit = std::fs::read_to_string(junit).unwrap();
+    assert!(junit.contains("tests=\"2\" failures=\"1\""));
+    let markdown = std::fs::read_to_string(markdown).unwrap();
+    assert!(markdown.starts_with("### Verdict: FAILED"));
+}
+
+#[test]
+fn test_invalid_inputs_exit_two() {
+    let output = verdict(&[
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("missing.yaml"),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("error: "));
+    assert!(stderr.contains("missing.yaml"));
+
+    let output = verdict(&[
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("passing.yaml"),
+        "--rules",
+        &fixture("passing.yaml"),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Invalid schema"));
+
+    let output = verdict(&[
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("bad_regex.yaml"),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Invalid regex '^[A-Z' in rules[1] (column 'name')"));
+}
+
+#[test]
+fn test_lenient_reports_unparsable_cells() {
+    let args = [
+        &fixture("dirty.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("passing.yaml"),
+    ];
+    let output = verdict(&args);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = verdict(&[&args[..], &["--lenient"]].concat());
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("FAIL: column 'age' — parses as Int — 1 of 2 values failed"));
+}
+
+#[test]
+fn test_batch_size_gives_same_report() {
+    let args = [
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("failing.toml"),
+    ];
+    let whole = verdict(&args);
+    let batched = verdict(&[&args[..], &["--batch-size", "1"]].concat());
+    assert_eq!(batched.status.code(), Some(1));
+
+    // everything but the elapsed time matches
+    let strip_timing = |output: &[u8]| -> Vec<String> {
+        String::from_utf8(output.to_vec())
+            .unwrap()
+            .lines()
+            .filter(|line| !line.starts_with("Validation report:"))
+            .map(str::to_string)
+            .collect()
+    };
+    assert_eq!(strip_timing(&batched.stdout), strip_timing(&whole.stdout));
+}
+
+#[test]
+fn test_strict_rejects_rules_that_dont_match_schema() {
+    let args = [
+        &fixture("people.csv"),
+        "--schema",
+        &fixture("schema.yaml"),
+        "--rules",
+        &fixture("mistyped.yaml"),
+    ];
+    let output = verdict(&args);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("requires an Int, Float or Decimal column, found 'name' (Str)"));
+
+    let output = verdict(&[&args[..], &["--strict"]].concat());
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("rules don't match the schema:"));
+    assert!(stderr.contains(
+        "\n  - Incompatible type in rules[1] (column 'age'): matches regex '^[0-9]+$' requires"
+    ));
+}
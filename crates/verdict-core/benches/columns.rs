@@ -0,0 +1,157 @@
+//! Compares the bitmap-backed columns against the `Vec<Option<T>>` layout
+//! they replaced. Run with `cargo bench -p verdict-core --bench columns`;
+//! the memory comparison is printed before the timings.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use verdict_core::dataset::{
+    FloatColumn, IntColumn, NumericOps, StrColumn,
+    ops::{ComparableOps, StringOps},
+};
+
+const ROWS: usize = 1_000_000;
+
+fn int_values() -> Vec<Option<i64>> {
+    (0..ROWS as i64)
+        .map(|i| (i % 10 != 0).then_some(i))
+        .collect()
+}
+
+fn float_values() -> Vec<Option<f64>> {
+    (0..ROWS)
+        .map(|i| (i % 10 != 0).then_some(i as f64 * 0.5))
+        .collect()
+}
+
+fn str_values() -> Vec<Option<String>> {
+    (0..ROWS)
+        .map(|i| (i % 10 != 0).then(|| format!("user-{}@example.com", i)))
+        .collect()
+}
+
+/// Heap bytes of the old layout, counting each string's own allocation.
+fn legacy_str_bytes(values: &[Option<String>]) -> usize {
+    size_of_val(values) + values.iter().flatten().map(String::capacity).sum::<usize>()
+}
+
+fn str_column_bytes(col: &StrColumn) -> usize {
+    size_of_val(col.offsets()) + col.data().len() + col.len().div_ceil(8)
+}
+
+fn report_memory() {
+    let ints = int_values();
+    let strs = str_values();
+    let int_col = IntColumn::from(ints.clone());
+    let str_col: StrColumn = strs.iter().map(Option::as_deref).collect();
+    let rows = [
+        (
+            "int",
+            size_of_val(&ints[..]),
+            size_of_val(int_col.values()) + ROWS.div_ceil(8),
+        ),
+        ("str", legacy_str_bytes(&strs), str_column_bytes(&str_col)),
+    ];
+    println!("memory for {} rows:", ROWS);
+    for (name, legacy, buffers) in rows {
+        println!(
+            "  {:<4} Vec<Option<T>> {:>6.1} MiB, buffers {:>6.1} MiB ({:.0}%)",
+            name,
+            legacy as f64 / (1 << 20) as f64,
+            buffers as f64 / (1 << 20) as f64,
+            buffers as f64 / legacy as f64 * 100.0
+        );
+    }
+}
+
+fn bench_int(c: &mut Criterion) {
+    let legacy = int_values();
+    let col = IntColumn::from(legacy.clone());
+    let mut group = c.benchmark_group("int");
+    group.bench_function("gt/legacy", |b| {
+        b.iter(|| {
+            let bound = black_box(500_000);
+            legacy
+                .iter()
+                .map(|v| v.map(|x| x > bound))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("gt/buffers", |b| b.iter(|| col.gt(black_box(500_000_i64))));
+    group.bench_function("sum/legacy", |b| {
+        b.iter(|| black_box(&legacy).iter().flatten().sum::<i64>())
+    });
+    group.bench_function("sum/buffers", |b| b.iter(|| black_box(&col).sum()));
+    group.finish();
+}
+
+fn bench_float(c: &mut Criterion) {
+    let legacy = float_values();
+    let col = FloatColumn::from(legacy.clone());
+    let mut group = c.benchmark_group("float");
+    group.bench_function("between/legacy", |b| {
+        b.iter(|| {
+            let (lower, upper) = black_box((1000.0, 2000.0));
+            legacy
+                .iter()
+                .map(|v| v.map(|x| x >= lower && x <= upper))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("between/buffers", |b| {
+        b.iter(|| col.between(black_box(1000.0), black_box(2000.0)))
+    });
+    group.finish();
+}
+
+fn bench_str(c: &mut Criterion) {
+    let legacy = str_values();
+    let col: StrColumn = legacy.iter().map(Option::as_deref).collect();
+    let indices: Vec<usize> = (0..ROWS).step_by(3).collect();
+    let mut group = c.benchmark_group("str");
+    group.bench_function("equal/legacy", |b| {
+        b.iter(|| {
+            let target = black_box("user-42@example.com");
+            legacy
+                .iter()
+                .map(|v| v.as_deref().map(|s| s == target))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("equal/buffers", |b| {
+        b.iter(|| col.equal(black_box("user-42@example.com")))
+    });
+    group.bench_function("length/legacy", |b| {
+        b.iter(|| {
+            legacy
+                .iter()
+                .map(|v| v.as_ref().map(String::len))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("length/buffers", |b| b.iter(|| col.length()));
+    group.bench_function("take/legacy", |b| {
+        b.iter(|| {
+            indices
+                .iter()
+                .map(|&i| legacy[i].clone())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("take/buffers", |b| b.iter(|| col.take(&indices)));
+    group.finish();
+}
+
+fn bench_columns(c: &mut Criterion) {
+    report_memory();
+    bench_int(c);
+    bench_float(c);
+    bench_str(c);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_columns
+}
+criterion_main!(benches);
@@ -1,7 +1,17 @@
-use crate::dataset::{
-    BoolColumn, Column, DataType, Dataset, FloatColumn, IntColumn, Schema, StrColumn,
+use crate::{
+    dataset::{
+        BoolColumn, CategoricalColumn, Coercion, Column, DataType, Dataset, DateColumn,
+        DateTimeColumn, DecimalColumn, ExtraColumns, Field, FloatColumn, IntColumn, OverlongRow,
+        Schema,
+    },
+    report::Report,
+    rules::{Rule, StreamingValidator, ValidationOptions},
 };
-use csv::Reader;
+use std::{collections::HashMap, fs::File};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use csv::{Reader, ReaderBuilder, Trim};
+use rust_decimal::Decimal;
 
 use thiserror::Error;
 
@@ -20,115 +30,633 @@ pub enum CsvLoadingError {
         value: String,
         expected: String,
     },
+
+    #[error("Missing required columns: {}", columns.join(", "))]
+    MissingColumns { columns: Vec<String> },
+
+    #[error("Column '{name}' appears more than once in the header")]
+    DuplicateColumn { name: String },
+
+    #[error("Expected column '{expected}' at position {position}, found {}", found_label(.found))]
+    ColumnOrderMismatch {
+        position: usize,
+        expected: String,
+        found: Option<String>,
+    },
+}
+
+/// CSV dialect and value parsing settings for `from_csv_with_options`.
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    pub delimiter: u8,
+    pub quote: u8,
+    /// Escape character for quotes inside quoted fields (e.g. `b'\\'`);
+    /// `None` means quotes are escaped by doubling them.
+    pub escape: Option<u8>,
+    /// When false, the first row is data and columns are matched to schema
+    /// fields by position; extra columns are named `column_1`, `column_2`, ...
+    pub has_headers: bool,
+    /// Lines starting with this byte are skipped.
+    pub comment: Option<u8>,
+    /// Strip leading and trailing whitespace from headers and values.
+    pub trim: bool,
+    /// Values read as null in every column.
+    pub null_values: Vec<String>,
+    /// Per-column null values; these replace `null_values` for that column.
+    pub column_null_values: HashMap<String, Vec<String>>,
+    /// Values read as true in Bool columns, compared case-insensitively.
+    pub true_values: Vec<String>,
+    /// Values read as false in Bool columns, compared case-insensitively.
+    pub false_values: Vec<String>,
+    /// `chrono` formats tried, in order, for Date columns.
+    pub date_formats: Vec<String>,
+    /// `chrono` formats tried, in order, for DateTime columns after RFC 3339.
+    /// A format may include an offset (`%z`, `%:z`); values without one get
+    /// `timezone`.
+    pub datetime_formats: Vec<String>,
+    /// Offset for DateTime values that don't carry one; `None` means UTC.
+    pub timezone: Option<FixedOffset>,
+    /// Turn unparsable cells and cells missing from short rows into nulls,
+    /// recorded in `Dataset::coercions`, instead of failing the load. Cells
+    /// past the header in long rows are dropped and the rows recorded in
+    /// `Dataset::overlong_rows`.
+    pub lenient: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            has_headers: true,
+            comment: None,
+            trim: false,
+            null_values: vec![String::new()],
+            column_null_values: HashMap::new(),
+            true_values: ["true", "1", "yes"].map(String::from).to_vec(),
+            false_values: ["false", "0", "no"].map(String::from).to_vec(),
+            date_formats: vec!["%Y-%m-%d".to_string()],
+            datetime_formats: ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+                .map(String::from)
+                .to_vec(),
+            timezone: None,
+            lenient: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    fn reader(&self, path: &str) -> Result<Reader<File>, csv::Error> {
+        ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none())
+            .has_headers(self.has_headers)
+            .comment(self.comment)
+            .flexible(self.lenient)
+            .trim(if self.trim { Trim::All } else { Trim::None })
+            .from_path(path)
+    }
+
+    /// File column names; generated as `column_N` when the file has no header row.
+    fn headers(&self, reader: &mut Reader<File>) -> Result<Vec<String>, csv::Error> {
+        let headers = reader.headers()?;
+        Ok(if self.has_headers {
+            headers.iter().map(|s| s.to_string()).collect()
+        } else {
+            (1..=headers.len())
+                .map(|i| format!("column_{}", i))
+                .collect()
+        })
+    }
+
+    fn null_values_for(&self, column: &str) -> &[String] {
+        self.column_null_values
+            .get(column)
+            .unwrap_or(&self.null_values)
+    }
+
+    fn parse_bool(&self, s: &str) -> Option<bool> {
+        if self.true_values.iter().any(|v| v.eq_ignore_ascii_case(s)) {
+            Some(true)
+        } else if self.false_values.iter().any(|v| v.eq_ignore_ascii_case(s)) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    fn parse_date(&self, s: &str) -> Option<NaiveDate> {
+        self.date_formats
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(s, format).ok())
+    }
+
+    fn parse_datetime(&self, s: &str) -> Option<DateTime<FixedOffset>> {
+        let timezone = self.timezone.unwrap_or(FixedOffset::east_opt(0)?);
+        DateTime::parse_from_rfc3339(s).ok().or_else(|| {
+            self.datetime_formats.iter().find_map(|format| {
+                DateTime::parse_from_str(s, format).ok().or_else(|| {
+                    NaiveDateTime::parse_from_str(s, format)
+                        .ok()?
+                        .and_local_timezone(timezone)
+                        .single()
+                })
+            })
+        })
+    }
+}
+
+/// Controls how `infer_schema` samples a CSV file.
+#[derive(Debug, Clone)]
+pub struct InferOptions {
+    /// Number of data rows to inspect; `None` reads the whole file.
+    pub sample_rows: Option<usize>,
+    /// Minimum fraction (0.0–1.0) of non-null sampled values that must parse
+    /// as Int, Float, Bool, Date or DateTime for that type to be picked over Str.
+    pub min_confidence: f64,
+    /// Dialect and parsing settings, shared with the loader.
+    pub csv: CsvOptions,
+}
+
+impl Default for InferOptions {
+    fn default() -> Self {
+        InferOptions {
+            sample_rows: Some(1000),
+            min_confidence: 1.0,
+            csv: CsvOptions::default(),
+        }
+    }
+}
+
+/// What was learned about one column while inferring a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInference {
+    pub name: String,
+    pub dtype: DataType,
+    /// Fraction of non-null sampled values that parse as `dtype`; 0.0 when the
+    /// column had no non-null values in the sample (it then defaults to Str).
+    pub confidence: f64,
+    pub null_count: usize,
+}
+
+/// An inferred `Schema` together with per-column confidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaInference {
+    pub schema: Schema,
+    pub fields: Vec<FieldInference>,
+    /// Number of data rows that were inspected.
+    pub rows_sampled: usize,
 }
 
 pub trait DatasetCsvExt {
-    fn from_csv(path: &str, schema: &Schema) -> Result<Dataset, CsvLoadingError>;
+    /// Loads a comma-separated file with a header row, using `CsvOptions::default()`.
+    fn from_csv(path: &str, schema: &Schema) -> Result<Dataset, CsvLoadingError> {
+        Self::from_csv_with_options(path, schema, &CsvOptions::default())
+    }
+
+    fn from_csv_with_options(
+        path: &str,
+        schema: &Schema,
+        options: &CsvOptions,
+    ) -> Result<Dataset, CsvLoadingError>;
+
+    /// Infers the schema with `infer_schema` and loads the file with it.
+    ///
+    /// When a column's type was picked with a confidence below 1.0, some of
+    /// its values are known not to parse, so the file is loaded leniently:
+    /// those cells become nulls recorded in `Dataset::coercions`. Otherwise
+    /// rows past the sample can still fail to parse when the sample was not
+    /// representative.
+    fn from_csv_infer(path: &str, options: &InferOptions) -> Result<Dataset, CsvLoadingError> {
+        let inference = infer_schema(path, options)?;
+        let mut csv = options.csv.clone();
+        csv.lenient |= inference
+            .fields
+            .iter()
+            .any(|f| f.dtype != DataType::Str && f.confidence < 1.0);
+        Self::from_csv_with_options(path, &inference.schema, &csv)
+    }
+}
+
+/// Guesses each column's type from the first rows of a CSV file, using the
+/// same parsing rules as `from_csv`. Types are tried from most to least
+/// specific: Int, Float, Bool, Date, DateTime, then Str as the fallback.
+/// Decimal and Categorical are never inferred; declare them in the schema.
+pub fn infer_schema(
+    path: &str,
+    options: &InferOptions,
+) -> Result<SchemaInference, CsvLoadingError> {
+    let mut reader = options.csv.reader(path)?;
+    let headers = options.csv.headers(&mut reader)?;
+    let null_values: Vec<&[String]> = headers
+        .iter()
+        .map(|h| options.csv.null_values_for(h))
+        .collect();
+
+    // per column: [non-null, int, float, bool, date, datetime] parse counts
+    let mut counts = vec![[0usize; 6]; headers.len()];
+    let mut null_counts = vec![0usize; headers.len()];
+    let mut rows_sampled = 0;
+
+    for record in reader.records() {
+        if options
+            .sample_rows
+            .is_some_and(|limit| rows_sampled >= limit)
+        {
+            break;
+        }
+        let record = record?;
+        for (i, field) in record.iter().enumerate().take(headers.len()) {
+            if null_values[i].iter().any(|v| v == field) {
+                null_counts[i] += 1;
+                continue;
+            }
+            let count = &mut counts[i];
+            count[0] += 1;
+            count[1] += field.parse::<i64>().is_ok() as usize;
+            count[2] += field.parse::<f64>().is_ok() as usize;
+            count[3] += options.csv.parse_bool(field).is_some() as usize;
+            count[4] += options.csv.parse_date(field).is_some() as usize;
+            count[5] += options.csv.parse_datetime(field).is_some() as usize;
+        }
+        rows_sampled += 1;
+    }
+
+    let fields: Vec<FieldInference> = headers
+        .into_iter()
+        .zip(counts)
+        .zip(null_counts)
+        .map(
+            |((name, [non_null, ints, floats, bools, dates, datetimes]), null_count)| {
+                let (dtype, confidence) = if non_null == 0 {
+                    (DataType::Str, 0.0)
+                } else {
+                    [
+                        (DataType::Int, ints),
+                        (DataType::Float, floats),
+                        (DataType::Bool, bools),
+                        (DataType::Date, dates),
+                        (DataType::DateTime, datetimes),
+                    ]
+                    .into_iter()
+                    .map(|(dtype, parsed)| (dtype, parsed as f64 / non_null as f64))
+                    .find(|(_, confidence)| *confidence >= options.min_confidence)
+                    .unwrap_or((DataType::Str, 1.0))
+                };
+                FieldInference {
+                    name,
+                    dtype,
+                    confidence,
+                    null_count,
+                }
+            },
+        )
+        .collect();
+
+    Ok(SchemaInference {
+        schema: Schema::new(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name.clone(), f.dtype.clone()))
+                .collect(),
+        ),
+        fields,
+        rows_sampled,
+    })
 }
 
 impl DatasetCsvExt for Dataset {
-    fn from_csv(path: &str, schema: &Schema) -> Result<Dataset, CsvLoadingError> {
-        let mut reader = Reader::from_path(path)?;
+    fn from_csv_with_options(
+        path: &str,
+        schema: &Schema,
+        options: &CsvOptions,
+    ) -> Result<Dataset, CsvLoadingError> {
+        CsvBatches::open(path, schema, options, usize::MAX)?.read_batch()
+    }
+}
+
+/// Reads a CSV file as a sequence of `Dataset`s of at most `batch_size` rows,
+/// each loaded the way `from_csv_with_options` loads a whole file. Only one
+/// batch is held in memory at a time.
+///
+/// The first batch is always yielded, even for a file with no data rows.
+/// Row numbers in parse errors count from the start of the file; rows in
+/// `Dataset::coercions` are relative to their batch.
+pub struct CsvBatches {
+    reader: Reader<File>,
+    schema: Schema,
+    options: CsvOptions,
+    file_headers: Vec<String>,
+    /// File column of every schema field.
+    positions: Vec<usize>,
+    /// Null values of every file column.
+    null_values: Vec<Vec<String>>,
+    batch_size: usize,
+    rows_read: usize,
+    started: bool,
+    done: bool,
+}
+
+impl CsvBatches {
+    pub fn open(
+        path: &str,
+        schema: &Schema,
+        options: &CsvOptions,
+        batch_size: usize,
+    ) -> Result<Self, CsvLoadingError> {
+        let mut reader = options.reader(path)?;
+        let file_headers = options.headers(&mut reader)?;
+        let positions = if options.has_headers {
+            resolve_columns(&file_headers, schema)?
+        } else {
+            resolve_positions(file_headers.len(), schema)?
+        };
+        // without headers the schema names the matched columns
+        let mut null_values: Vec<Vec<String>> = file_headers
+            .iter()
+            .map(|h| options.null_values_for(h).to_vec())
+            .collect();
+        for (field, &col_idx) in schema.fields.iter().zip(&positions) {
+            null_values[col_idx] = options.null_values_for(&field.name).to_vec();
+        }
+
+        Ok(CsvBatches {
+            reader,
+            schema: schema.clone(),
+            options: options.clone(),
+            file_headers,
+            positions,
+            null_values,
+            batch_size: batch_size.max(1),
+            rows_read: 0,
+            started: false,
+            done: false,
+        })
+    }
 
-        let headers: Vec<String> = reader.headers()?.iter().map(|s| s.to_string()).collect();
-        let num_columns = headers.len();
+    fn read_batch(&mut self) -> Result<Dataset, CsvLoadingError> {
+        let num_columns = self.file_headers.len();
         let mut raw_columns: Vec<Vec<Option<String>>> = vec![vec![]; num_columns];
+        // rows, per file column, that were too short to have the cell
+        let mut missing: Vec<Vec<usize>> = vec![vec![]; num_columns];
+        // only a lenient (flexible) reader lets rows run past the header
+        let mut overlong_rows: Vec<OverlongRow> = Vec::new();
+        let mut record = csv::StringRecord::new();
+        let mut batch_rows = 0;
 
-        for record in reader.records() {
-            let record = record?;
-            for (i, field) in record.iter().enumerate() {
-                let value = if field.is_empty() {
-                    None
-                } else {
-                    Some(field.to_string())
+        while batch_rows < self.batch_size && self.reader.read_record(&mut record)? {
+            if record.len() > num_columns {
+                overlong_rows.push(OverlongRow {
+                    row: batch_rows,
+                    extra: record.iter().skip(num_columns).map(String::from).collect(),
+                });
+            }
+            for (i, raw_col) in raw_columns.iter_mut().enumerate() {
+                let value = match record.get(i) {
+                    Some(field) if self.null_values[i].iter().any(|v| v == field) => None,
+                    Some(field) => Some(field.to_string()),
+                    None => {
+                        missing[i].push(batch_rows);
+                        None
+                    }
                 };
-                raw_columns[i].push(value);
+                raw_col.push(value);
             }
+            batch_rows += 1;
         }
 
-        let mut columns: Vec<Column> = Vec::with_capacity(schema.fields.len());
+        let row_offset = self.rows_read;
+        self.rows_read += batch_rows;
+        let options = &self.options;
 
-        for (col_idx, field) in schema.fields.iter().enumerate() {
+        let mut headers: Vec<String> = Vec::with_capacity(num_columns);
+        let mut columns: Vec<Column> = Vec::with_capacity(num_columns);
+        let mut coercions: Vec<Coercion> = Vec::new();
+
+        for (field, &col_idx) in self.schema.fields.iter().zip(&self.positions) {
             let raw_col = &raw_columns[col_idx];
+            let first_coercion = coercions.len();
+            let mut column_coercions: Option<&mut Vec<Coercion>> =
+                options.lenient.then_some(&mut coercions);
+            if let Some(coercions) = column_coercions.as_mut() {
+                coercions.extend(missing[col_idx].iter().map(|&row| Coercion {
+                    row,
+                    column: field.name.clone(),
+                    value: None,
+                    expected: field.dtype.clone(),
+                }));
+            }
 
             let column = match field.dtype {
-                DataType::Int => {
-                    let parsed: Result<Vec<Option<i64>>, _> = raw_col
-                        .iter()
-                        .enumerate()
-                        .map(|(row_idx, val)| {
-                            val.as_ref()
-                                .map(|s| {
-                                    s.parse::<i64>().map_err(|_| CsvLoadingError::ParseError {
-                                        column: field.name.clone(),
-                                        row: row_idx,
-                                        value: s.clone(),
-                                        expected: "Int".to_string(),
-                                    })
-                                })
-                                .transpose()
-                        })
-                        .collect();
-                    Column::Int(IntColumn(parsed?))
-                }
+                DataType::Int => Column::Int(IntColumn::from(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| s.parse::<i64>().ok(),
+                    column_coercions,
+                )?)),
+                DataType::Float => Column::Float(FloatColumn::from(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| s.parse::<f64>().ok(),
+                    column_coercions,
+                )?)),
+                DataType::Decimal => Column::Decimal(DecimalColumn(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| Decimal::from_str_exact(s).ok(),
+                    column_coercions,
+                )?)),
+                DataType::Str => Column::Str(raw_col.iter().map(Option::as_deref).collect()),
+                DataType::Categorical => Column::Categorical(CategoricalColumn::from_values(
+                    raw_col.iter().map(Option::as_deref),
+                )),
+                DataType::Bool => Column::Bool(BoolColumn::from(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| options.parse_bool(s),
+                    column_coercions,
+                )?)),
+                DataType::Date => Column::Date(DateColumn(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| options.parse_date(s),
+                    column_coercions,
+                )?)),
+                DataType::DateTime => Column::DateTime(DateTimeColumn(parse_column(
+                    raw_col,
+                    field,
+                    row_offset,
+                    |s| options.parse_datetime(s),
+                    column_coercions,
+                )?)),
+            };
+            coercions[first_coercion..].sort_by_key(|c| c.row);
 
-                DataType::Float => {
-                    let parsed: Result<Vec<Option<f64>>, _> = raw_col
-                        .iter()
-                        .enumerate()
-                        .map(|(row_idx, val)| {
-                            val.as_ref()
-                                .map(|s| {
-                                    s.parse::<f64>().map_err(|_| CsvLoadingError::ParseError {
-                                        column: field.name.clone(),
-                                        row: row_idx,
-                                        value: s.clone(),
-                                        expected: "Float".to_string(),
-                                    })
-                                })
-                                .transpose()
-                        })
-                        .collect();
-                    Column::Float(FloatColumn(parsed?))
-                }
+            headers.push(field.name.clone());
+            columns.push(column);
+        }
 
-                DataType::Str => {
-                    let parsed: Vec<Option<String>> = raw_col.clone();
-                    Column::Str(StrColumn(parsed))
+        if self.schema.extra_columns == ExtraColumns::KeepAsStr {
+            for (col_idx, name) in self.file_headers.iter().enumerate() {
+                if !self.positions.contains(&col_idx) {
+                    headers.push(name.clone());
+                    let raw_col = &raw_columns[col_idx];
+                    columns.push(Column::Str(raw_col.iter().map(Option::as_deref).collect()));
                 }
+            }
+        }
 
-                DataType::Bool => {
-                    let parsed: Result<Vec<Option<bool>>, _> = raw_col
-                        .iter()
-                        .enumerate()
-                        .map(|(row_idx, val)| {
-                            val.as_ref()
-                                .map(|s| {
-                                    parse_bool(s).ok_or_else(|| CsvLoadingError::ParseError {
-                                        column: field.name.clone(),
-                                        row: row_idx,
-                                        value: s.clone(),
-                                        expected: "Bool".to_string(),
-                                    })
-                                })
-                                .transpose()
-                        })
-                        .collect();
-                    Column::Bool(BoolColumn(parsed?))
-                }
+        Ok(Dataset {
+            headers,
+            columns,
+            coercions,
+            overlong_rows,
+        })
+    }
+}
+
+impl Iterator for CsvBatches {
+    type Item = Result<Dataset, CsvLoadingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let batch = self.read_batch();
+        let first = !self.started;
+        self.started = true;
+        match batch {
+            Ok(batch) if batch.shape().0 == 0 && !first => {
+                self.done = true;
+                None
+            }
+            Ok(batch) => Some(Ok(batch)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Validates a CSV file `batch_size` rows at a time with a
+/// `StreamingValidator`, giving the same report as loading the whole file
+/// with `from_csv_with_options` and calling `validate_with_options`.
+pub fn validate_csv(
+    path: &str,
+    schema: &Schema,
+    rules: &[Rule],
+    csv_options: &CsvOptions,
+    options: &ValidationOptions,
+    batch_size: usize,
+) -> Result<Report, CsvLoadingError> {
+    let mut validator = StreamingValidator::new(rules, options.clone());
+    for batch in CsvBatches::open(path, schema, csv_options, batch_size)? {
+        validator.update(&batch?);
+    }
+    Ok(validator.finish())
+}
+
+/// Parses a raw column as `field.dtype`. Unparsable cells are an error, or
+/// with `coercions` (lenient loading) become null and are recorded there.
+fn parse_column<T>(
+    raw_col: &[Option<String>],
+    field: &Field,
+    row_offset: usize,
+    parse: impl Fn(&str) -> Option<T>,
+    mut coercions: Option<&mut Vec<Coercion>>,
+) -> Result<Vec<Option<T>>, CsvLoadingError> {
+    raw_col
+        .iter()
+        .enumerate()
+        .map(|(row_idx, val)| {
+            let Some(s) = val else {
+                return Ok(None);
             };
+            match (parse(s), coercions.as_mut()) {
+                (Some(v), _) => Ok(Some(v)),
+                (None, Some(coercions)) => {
+                    coercions.push(Coercion {
+                        row: row_idx,
+                        column: field.name.clone(),
+                        value: Some(s.clone()),
+                        expected: field.dtype.clone(),
+                    });
+                    Ok(None)
+                }
+                (None, None) => Err(CsvLoadingError::ParseError {
+                    column: field.name.clone(),
+                    row: row_offset + row_idx,
+                    value: s.clone(),
+                    expected: format!("{:?}", field.dtype),
+                }),
+            }
+        })
+        .collect()
+}
 
-            columns.push(column);
+/// Finds the file column for every schema field, by header name.
+fn resolve_columns(headers: &[String], schema: &Schema) -> Result<Vec<usize>, CsvLoadingError> {
+    if schema.ordered {
+        for (position, field) in schema.fields.iter().enumerate() {
+            let found = headers.get(position);
+            if found != Some(&field.name) {
+                return Err(CsvLoadingError::ColumnOrderMismatch {
+                    position,
+                    expected: field.name.clone(),
+                    found: found.cloned(),
+                });
+            }
+        }
+    }
+
+    let mut positions = Vec::with_capacity(schema.fields.len());
+    let mut missing = Vec::new();
+    for field in &schema.fields {
+        let mut matches = headers
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| **h == field.name);
+        match (matches.next(), matches.next()) {
+            (Some((idx, _)), None) => positions.push(idx),
+            (Some(_), Some(_)) => {
+                return Err(CsvLoadingError::DuplicateColumn {
+                    name: field.name.clone(),
+                });
+            }
+            (None, _) => missing.push(field.name.clone()),
         }
+    }
+    if !missing.is_empty() {
+        return Err(CsvLoadingError::MissingColumns { columns: missing });
+    }
+    Ok(positions)
+}
 
-        Ok(Dataset { headers, columns })
+/// Pairs schema fields with file columns by position, for files without a header row.
+fn resolve_positions(width: usize, schema: &Schema) -> Result<Vec<usize>, CsvLoadingError> {
+    if schema.fields.len() > width {
+        return Err(CsvLoadingError::MissingColumns {
+            columns: schema.fields[width..]
+                .iter()
+                .map(|f| f.name.clone())
+                .collect(),
+        });
     }
+    Ok((0..schema.fields.len()).collect())
 }
 
-fn parse_bool(s: &str) -> Option<bool> {
-    match s.to_lowercase().as_str() {
-        "true" | "1" | "yes" => Some(true),
-        "false" | "0" | "no" => Some(false),
-        _ => None,
+fn found_label(found: &Option<String>) -> String {
+    match found {
+        Some(name) => format!("'{}'", name),
+        None => "end of header".to_string(),
     }
 }
@@ -0,0 +1,242 @@
+//! Packed bitmaps, one bit per row, as used for column validity.
+
+use std::ops::Range;
+
+pub(crate) const WORD_BITS: usize = u64::BITS as usize;
+
+/// A packed sequence of bits. Column validity follows the Apache Arrow
+/// convention: a set bit marks a non-null value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bitmap {
+    /// Bits beyond `len` in the last word are always zero.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitmap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Bitmap {
+            words: Vec::with_capacity(bits.div_ceil(WORD_BITS)),
+            len: 0,
+        }
+    }
+
+    /// `len` bits, all set to `value`.
+    pub fn filled(len: usize, value: bool) -> Self {
+        let word = if value { u64::MAX } else { 0 };
+        Bitmap::from_words(vec![word; len.div_ceil(WORD_BITS)], len)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> bool {
+        assert!(
+            idx < self.len,
+            "bit {} out of range for length {}",
+            idx,
+            self.len
+        );
+        self.words[idx / WORD_BITS] >> (idx % WORD_BITS) & 1 == 1
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, value: bool) {
+        assert!(
+            idx < self.len,
+            "bit {} out of range for length {}",
+            idx,
+            self.len
+        );
+        let mask = 1 << (idx % WORD_BITS);
+        if value {
+            self.words[idx / WORD_BITS] |= mask;
+        } else {
+            self.words[idx / WORD_BITS] &= !mask;
+        }
+    }
+
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(WORD_BITS) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        if value {
+            self.set(self.len - 1, true);
+        }
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Whether every bit is set, e.g. a column without nulls.
+    pub fn all_set(&self) -> bool {
+        self.count_ones() == self.len
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: self.words.iter(),
+            current: 0,
+            bit: WORD_BITS,
+            remaining: self.len,
+        }
+    }
+
+    /// The packed words, least significant bit first; bits past `len` are
+    /// zero.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Copies the bits in `range` into a new bitmap.
+    pub fn slice(&self, range: Range<usize>) -> Bitmap {
+        assert!(range.end <= self.len, "range out of bounds");
+        if range.start.is_multiple_of(WORD_BITS) {
+            // word-aligned: copy whole words and clear the tail
+            let len = range.len();
+            let start = range.start / WORD_BITS;
+            let words = self.words[start..start + len.div_ceil(WORD_BITS)].to_vec();
+            return Bitmap::from_words(words, len);
+        }
+        range.map(|i| self.get(i)).collect()
+    }
+
+    /// Builds a bitmap from the bits at `indices`, in the given order.
+    pub fn take(&self, indices: &[usize]) -> Bitmap {
+        indices.iter().map(|&i| self.get(i)).collect()
+    }
+
+    /// Appends the bits of `other`.
+    pub fn extend(&mut self, other: &Bitmap) {
+        if self.len.is_multiple_of(WORD_BITS) {
+            self.words.extend_from_slice(&other.words);
+            self.len += other.len;
+            return;
+        }
+        for bit in other.iter() {
+            self.push(bit);
+        }
+    }
+
+    /// Indices of the set bits, in ascending order.
+    pub fn set_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.indices(false)
+    }
+
+    /// Indices of the unset bits, in ascending order.
+    pub fn unset_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.indices(true)
+    }
+
+    fn indices(&self, invert: bool) -> impl Iterator<Item = usize> + '_ {
+        let len = self.len;
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut word = if invert { !word } else { word };
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * WORD_BITS + bit)
+            })
+            .take_while(move |&idx| idx < len)
+        })
+    }
+
+    pub fn and(&self, other: &Bitmap) -> Bitmap {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    pub fn or(&self, other: &Bitmap) -> Bitmap {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+    /// Bits set in `self` but not in `other`.
+    pub fn and_not(&self, other: &Bitmap) -> Bitmap {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    pub fn not(&self) -> Bitmap {
+        Bitmap::from_words(self.words.iter().map(|w| !w).collect(), self.len)
+    }
+
+    /// Panics if the bitmaps differ in length.
+    fn zip_words(&self, other: &Bitmap, f: impl Fn(u64, u64) -> u64) -> Bitmap {
+        assert_eq!(self.len, other.len, "bitmaps differ in length");
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| f(*a, *b))
+            .collect();
+        Bitmap::from_words(words, self.len)
+    }
+
+    /// Wraps `words` holding `len` bits, clearing any bits past `len`.
+    pub(crate) fn from_words(mut words: Vec<u64>, len: usize) -> Bitmap {
+        words.truncate(len.div_ceil(WORD_BITS));
+        let tail = len % WORD_BITS;
+        if tail != 0 {
+            *words.last_mut().unwrap() &= (1 << tail) - 1;
+        }
+        Bitmap { words, len }
+    }
+}
+
+impl FromIterator<bool> for Bitmap {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut bitmap = Bitmap::with_capacity(iter.size_hint().0);
+        for bit in iter {
+            bitmap.push(bit);
+        }
+        bitmap
+    }
+}
+
+/// Iterates a bitmap's bits, one word at a time.
+pub struct Iter<'a> {
+    words: std::slice::Iter<'a, u64>,
+    current: u64,
+    bit: usize,
+    remaining: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.bit == WORD_BITS {
+            self.current = *self.words.next()?;
+            self.bit = 0;
+        }
+        let value = self.current >> self.bit & 1 == 1;
+        self.bit += 1;
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
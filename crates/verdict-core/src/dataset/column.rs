@@ -1,8 +1,29 @@
-use std::collections::HashSet;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    ops::Range,
+    sync::Arc,
+};
 
-use crate::dataset::ops::{ComparableOps, NumericOps, StringOps};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use regex::Regex;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 
-#[derive(Debug, Clone)]
+use crate::dataset::{
+    DataType, Mask,
+    bitmap::{Bitmap, WORD_BITS},
+    ops::{
+        ComparableOps, DateTimeOps, DecimalOps, NumericOps, StringOps, decimal_from_f64,
+        fits_precision,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum InSetValues {
     IntSet(Vec<i64>),
     FloatSet(Vec<f64>),
@@ -14,24 +35,75 @@ pub enum Column {
     Int(IntColumn),
     Float(FloatColumn),
     Str(StrColumn),
+    Categorical(CategoricalColumn),
     Bool(BoolColumn),
+    Decimal(DecimalColumn),
+    Date(DateColumn),
+    DateTime(DateTimeColumn),
+}
+
+/// Fixed-width values in one contiguous buffer, with nulls tracked in a
+/// validity bitmap. Null slots hold `T::default()`.
+#[derive(Clone)]
+pub struct PrimitiveColumn<T> {
+    values: Vec<T>,
+    validity: Bitmap,
+}
+
+pub type IntColumn = PrimitiveColumn<i64>;
+pub type FloatColumn = PrimitiveColumn<f64>;
+
+/// Strings stored back to back in one buffer; row `i` spans
+/// `data[offsets[i]..offsets[i + 1]]`, which is empty for nulls.
+#[derive(Clone)]
+pub struct StrColumn {
+    offsets: Vec<usize>,
+    data: String,
+    validity: Bitmap,
 }
 
+/// Dictionary-encoded strings: each row holds an index into `dictionary`.
+/// Saves memory on low-cardinality columns, and string checks run once per
+/// distinct value instead of once per row.
 #[derive(Clone)]
-pub struct IntColumn(pub Vec<Option<i64>>);
+pub struct CategoricalColumn {
+    pub codes: Vec<Option<u32>>,
+    /// Distinct values, shared by the column's slices, so it may hold values
+    /// no row refers to.
+    pub dictionary: Arc<[String]>,
+}
+
+/// Values and validity both packed one bit per row.
+#[derive(Clone)]
+pub struct BoolColumn {
+    values: Bitmap,
+    validity: Bitmap,
+}
 
+/// Exact fixed-point numbers, each keeping the scale it was given with.
 #[derive(Clone)]
-pub struct FloatColumn(pub Vec<Option<f64>>);
+pub struct DecimalColumn(pub Vec<Option<Decimal>>);
 
 #[derive(Clone)]
-pub struct StrColumn(pub Vec<Option<String>>);
+pub struct DateColumn(pub Vec<Option<NaiveDate>>);
 
+/// Points in time, each keeping the UTC offset it was given with.
 #[derive(Clone)]
-pub struct BoolColumn(pub Vec<Option<bool>>);
+pub struct DateTimeColumn(pub Vec<Option<DateTime<FixedOffset>>>);
+
+impl<T: Copy + Default> PrimitiveColumn<T> {
+    /// Panics if `values` and `validity` differ in length.
+    pub fn new(values: Vec<T>, validity: Bitmap) -> Self {
+        assert_eq!(
+            values.len(),
+            validity.len(),
+            "values and validity differ in length"
+        );
+        PrimitiveColumn { values, validity }
+    }
 
-impl IntColumn {
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.values.len()
     }
 
     #[must_use]
@@ -40,11 +112,354 @@ impl IntColumn {
     }
 
     pub fn not_null_count(&self) -> usize {
-        self.0.iter().filter(|v| v.is_some()).count()
+        self.validity.count_ones()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<T> {
+        (idx < self.len() && self.validity.get(idx)).then(|| self.values[idx])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<T>> + '_ {
+        self.values
+            .iter()
+            .zip(self.validity.iter())
+            .map(|(v, valid)| valid.then_some(*v))
+    }
+
+    /// The non-null values, in row order.
+    pub fn valid_values(&self) -> impl Iterator<Item = T> + '_ {
+        self.values
+            .chunks(WORD_BITS)
+            .zip(self.validity.words())
+            .flat_map(|(chunk, &word)| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .filter(move |(bit, _)| word >> bit & 1 == 1)
+                    .map(|(_, v)| *v)
+            })
+    }
+
+    /// The raw values buffer, including the placeholders at null slots.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    /// Applies `f` to every non-null value.
+    pub(crate) fn map<R: Clone>(&self, f: impl Fn(T) -> R) -> Vec<Option<R>> {
+        let mut out = vec![None; self.len()];
+        let chunks = out.chunks_mut(WORD_BITS).zip(self.values.chunks(WORD_BITS));
+        for ((out, values), word) in chunks.zip(self.validity.words()) {
+            for (bit, (out, v)) in out.iter_mut().zip(values).enumerate() {
+                if word >> bit & 1 == 1 {
+                    *out = Some(f(*v));
+                }
+            }
+        }
+        out
+    }
+
+    /// Evaluates `f` on every row. `f` also runs on the placeholders at null
+    /// slots, which keeps the loop branch-free, so it must not panic on
+    /// `T::default()`.
+    pub(crate) fn mask(&self, f: impl Fn(T) -> bool) -> Mask {
+        let words = self
+            .values
+            .chunks(WORD_BITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (bit, v)| word | u64::from(f(*v)) << bit)
+            })
+            .collect();
+        Mask::new(Bitmap::from_words(words, self.len()), self.validity.clone())
+    }
+
+    pub fn take(&self, indices: &[usize]) -> Self {
+        PrimitiveColumn {
+            values: indices.iter().map(|&i| self.values[i]).collect(),
+            validity: self.validity.take(indices),
+        }
+    }
+
+    pub fn slice(&self, rows: Range<usize>) -> Self {
+        PrimitiveColumn {
+            values: self.values[rows.clone()].to_vec(),
+            validity: self.validity.slice(rows),
+        }
+    }
+}
+
+impl<T: Copy + Default> FromIterator<Option<T>> for PrimitiveColumn<T> {
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut values = Vec::with_capacity(iter.size_hint().0);
+        let mut validity = Bitmap::with_capacity(iter.size_hint().0);
+        for value in iter {
+            values.push(value.unwrap_or_default());
+            validity.push(value.is_some());
+        }
+        PrimitiveColumn { values, validity }
+    }
+}
+
+impl<T: Copy + Default> From<Vec<Option<T>>> for PrimitiveColumn<T> {
+    fn from(values: Vec<Option<T>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl StrColumn {
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn not_null_count(&self) -> usize {
+        self.validity.count_ones()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        (idx < self.len() && self.validity.get(idx))
+            .then(|| &self.data[self.offsets[idx]..self.offsets[idx + 1]])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        self.offsets
+            .windows(2)
+            .zip(self.validity.iter())
+            .map(|(span, valid)| valid.then(|| &self.data[span[0]..span[1]]))
+    }
+
+    /// Byte offsets into `data`, one more than there are rows.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Every non-null value, concatenated.
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    /// Byte length of every value, read from the offsets alone.
+    pub(crate) fn lengths(&self) -> Vec<Option<usize>> {
+        self.offsets
+            .windows(2)
+            .zip(self.validity.iter())
+            .map(|(span, valid)| valid.then(|| span[1] - span[0]))
+            .collect()
+    }
+
+    pub(crate) fn mask(&self, f: impl Fn(&str) -> bool) -> Mask {
+        self.mask_spans(|data, span| f(&data[span]))
+    }
+
+    /// Like `mask`, but passes `data` and each value's byte range, so checks
+    /// that only need the length can skip reading the bytes.
+    pub(crate) fn mask_spans(&self, f: impl Fn(&str, Range<usize>) -> bool) -> Mask {
+        let mut values = Bitmap::filled(self.len(), false);
+        for row in self.validity.set_indices() {
+            if f(&self.data, self.offsets[row]..self.offsets[row + 1]) {
+                values.set(row, true);
+            }
+        }
+        Mask::new(values, self.validity.clone())
+    }
+
+    pub fn take(&self, indices: &[usize]) -> Self {
+        indices.iter().map(|&i| self.get(i)).collect()
+    }
+
+    pub fn slice(&self, rows: Range<usize>) -> Self {
+        let (start, end) = (self.offsets[rows.start], self.offsets[rows.end]);
+        StrColumn {
+            offsets: self.offsets[rows.start..=rows.end]
+                .iter()
+                .map(|o| o - start)
+                .collect(),
+            data: self.data[start..end].to_string(),
+            validity: self.validity.slice(rows),
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<Option<S>> for StrColumn {
+    fn from_iter<I: IntoIterator<Item = Option<S>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut offsets = Vec::with_capacity(iter.size_hint().0 + 1);
+        let mut data = String::new();
+        let mut validity = Bitmap::with_capacity(iter.size_hint().0);
+        offsets.push(0);
+        for value in iter {
+            if let Some(value) = &value {
+                data.push_str(value.as_ref());
+            }
+            offsets.push(data.len());
+            validity.push(value.is_some());
+        }
+        StrColumn {
+            offsets,
+            data,
+            validity,
+        }
+    }
+}
+
+impl From<Vec<Option<String>>> for StrColumn {
+    fn from(values: Vec<Option<String>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl CategoricalColumn {
+    /// Encodes `values`, assigning codes in order of first appearance.
+    pub fn from_values<S: AsRef<str>>(values: impl IntoIterator<Item = Option<S>>) -> Self {
+        let mut index: HashMap<String, u32> = HashMap::new();
+        let mut dictionary = Vec::new();
+        let codes = values
+            .into_iter()
+            .map(|value| {
+                let value = value?;
+                let value = value.as_ref();
+                if let Some(&code) = index.get(value) {
+                    return Some(code);
+                }
+                let code = u32::try_from(dictionary.len()).expect("more than u32::MAX categories");
+                index.insert(value.to_string(), code);
+                dictionary.push(value.to_string());
+                Some(code)
+            })
+            .collect();
+        CategoricalColumn {
+            codes,
+            dictionary: dictionary.into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn not_null_count(&self) -> usize {
+        self.codes.iter().filter(|v| v.is_some()).count()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        let code = (*self.codes.get(idx)?)?;
+        Some(&self.dictionary[code as usize])
+    }
+
+    pub fn to_str_column(&self) -> StrColumn {
+        (0..self.len()).map(|i| self.get(i)).collect()
+    }
+
+    /// `map_dictionary` for checks.
+    pub(crate) fn mask_dictionary(&self, f: impl Fn(&str) -> bool) -> Mask {
+        let results: Vec<bool> = self.dictionary.iter().map(|v| f(v)).collect();
+        self.codes
+            .iter()
+            .map(|code| code.map(|c| results[c as usize]))
+            .collect()
+    }
+
+    /// Evaluates `f` once per dictionary entry and spreads the results over
+    /// the rows.
+    pub(crate) fn map_dictionary<R: Clone>(&self, f: impl Fn(&str) -> R) -> Vec<Option<R>> {
+        let results: Vec<R> = self.dictionary.iter().map(|v| f(v)).collect();
+        self.codes
+            .iter()
+            .map(|code| code.map(|c| results[c as usize].clone()))
+            .collect()
+    }
+}
+
+impl BoolColumn {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn not_null_count(&self) -> usize {
+        self.validity.count_ones()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        (idx < self.len() && self.validity.get(idx)).then(|| self.values.get(idx))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<bool>> + '_ {
+        self.values
+            .iter()
+            .zip(self.validity.iter())
+            .map(|(v, valid)| valid.then_some(v))
+    }
+
+    /// The raw values, with unset bits at null slots.
+    pub fn values(&self) -> &Bitmap {
+        &self.values
+    }
+
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    pub fn take(&self, indices: &[usize]) -> Self {
+        BoolColumn {
+            values: self.values.take(indices),
+            validity: self.validity.take(indices),
+        }
+    }
+
+    pub fn slice(&self, rows: Range<usize>) -> Self {
+        BoolColumn {
+            values: self.values.slice(rows.clone()),
+            validity: self.validity.slice(rows),
+        }
+    }
+}
+
+impl FromIterator<Option<bool>> for BoolColumn {
+    fn from_iter<I: IntoIterator<Item = Option<bool>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut values = Bitmap::with_capacity(iter.size_hint().0);
+        let mut validity = Bitmap::with_capacity(iter.size_hint().0);
+        for value in iter {
+            values.push(value.unwrap_or_default());
+            validity.push(value.is_some());
+        }
+        BoolColumn { values, validity }
+    }
+}
+
+impl From<Vec<Option<bool>>> for BoolColumn {
+    fn from(values: Vec<Option<bool>>) -> Self {
+        values.into_iter().collect()
     }
 }
 
-impl FloatColumn {
+impl DecimalColumn {
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -59,7 +474,7 @@ impl FloatColumn {
     }
 }
 
-impl StrColumn {
+impl DateColumn {
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -74,7 +489,7 @@ impl StrColumn {
     }
 }
 
-impl BoolColumn {
+impl DateTimeColumn {
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -95,7 +510,11 @@ impl Column {
             Column::Int(col) => col.len(),
             Column::Float(col) => col.len(),
             Column::Str(col) => col.len(),
+            Column::Categorical(col) => col.len(),
             Column::Bool(col) => col.len(),
+            Column::Decimal(col) => col.len(),
+            Column::Date(col) => col.len(),
+            Column::DateTime(col) => col.len(),
         }
     }
 
@@ -103,12 +522,29 @@ impl Column {
         self.len() == 0
     }
 
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Column::Int(_) => DataType::Int,
+            Column::Float(_) => DataType::Float,
+            Column::Str(_) => DataType::Str,
+            Column::Categorical(_) => DataType::Categorical,
+            Column::Bool(_) => DataType::Bool,
+            Column::Decimal(_) => DataType::Decimal,
+            Column::Date(_) => DataType::Date,
+            Column::DateTime(_) => DataType::DateTime,
+        }
+    }
+
     pub fn is_null(&self) -> Vec<bool> {
         match self {
-            Column::Int(col) => col.0.iter().map(|v| v.is_none()).collect(),
-            Column::Float(col) => col.0.iter().map(|v| v.is_none()).collect(),
-            Column::Str(col) => col.0.iter().map(|v| v.is_none()).collect(),
-            Column::Bool(col) => col.0.iter().map(|v| v.is_none()).collect(),
+            Column::Int(col) => col.validity().iter().map(|valid| !valid).collect(),
+            Column::Float(col) => col.validity().iter().map(|valid| !valid).collect(),
+            Column::Str(col) => col.validity().iter().map(|valid| !valid).collect(),
+            Column::Categorical(col) => col.codes.iter().map(|v| v.is_none()).collect(),
+            Column::Bool(col) => col.validity().iter().map(|valid| !valid).collect(),
+            Column::Decimal(col) => col.0.iter().map(|v| v.is_none()).collect(),
+            Column::Date(col) => col.0.iter().map(|v| v.is_none()).collect(),
+            Column::DateTime(col) => col.0.iter().map(|v| v.is_none()).collect(),
         }
     }
 
@@ -117,7 +553,11 @@ impl Column {
             Column::Int(col) => col.len() - col.not_null_count(),
             Column::Float(col) => col.len() - col.not_null_count(),
             Column::Str(col) => col.len() - col.not_null_count(),
+            Column::Categorical(col) => col.len() - col.not_null_count(),
             Column::Bool(col) => col.len() - col.not_null_count(),
+            Column::Decimal(col) => col.len() - col.not_null_count(),
+            Column::Date(col) => col.len() - col.not_null_count(),
+            Column::DateTime(col) => col.len() - col.not_null_count(),
         }
     }
 
@@ -126,17 +566,29 @@ impl Column {
             Column::Int(col) => col.not_null_count(),
             Column::Float(col) => col.not_null_count(),
             Column::Str(col) => col.not_null_count(),
+            Column::Categorical(col) => col.not_null_count(),
             Column::Bool(col) => col.not_null_count(),
+            Column::Decimal(col) => col.not_null_count(),
+            Column::Date(col) => col.not_null_count(),
+            Column::DateTime(col) => col.not_null_count(),
         }
     }
 
     pub fn unique_count(&self) -> usize {
         match self {
-            Column::Int(col) => col.0.iter().collect::<HashSet<_>>().len(),
-            Column::Str(col) => col.0.iter().collect::<HashSet<_>>().len(),
-            Column::Bool(col) => col.0.iter().collect::<HashSet<_>>().len(),
-            Column::Float(col) => col
+            Column::Int(col) => col.iter().collect::<HashSet<_>>().len(),
+            Column::Str(col) => col.iter().collect::<HashSet<_>>().len(),
+            Column::Categorical(col) => col.codes.iter().collect::<HashSet<_>>().len(),
+            Column::Bool(col) => col.iter().collect::<HashSet<_>>().len(),
+            Column::Decimal(col) => col
                 .0
+                .iter()
+                .map(|v| v.map(|d| d.normalize().serialize()))
+                .collect::<HashSet<_>>()
+                .len(),
+            Column::Date(col) => col.0.iter().collect::<HashSet<_>>().len(),
+            Column::DateTime(col) => col.0.iter().collect::<HashSet<_>>().len(),
+            Column::Float(col) => col
                 .iter()
                 .map(|v| v.map(|f| f.to_bits()))
                 .collect::<HashSet<_>>()
@@ -148,24 +600,111 @@ impl Column {
         self.len() - self.unique_count()
     }
 
-    pub fn is_in(&self, other: &InSetValues) -> Vec<Option<bool>> {
+    /// Marks every row whose value already appeared earlier in the column.
+    /// Nulls are treated as one distinct value, matching `unique_count`.
+    /// Decimals written with different scales (`1.5`, `1.50`) are equal.
+    pub fn duplicated(&self) -> Vec<bool> {
+        fn mark<T: Eq + std::hash::Hash>(values: impl Iterator<Item = T>) -> Vec<bool> {
+            let mut seen = HashSet::new();
+            values.map(|v| !seen.insert(v)).collect()
+        }
+        match self {
+            Column::Int(col) => mark(col.iter()),
+            Column::Float(col) => mark(col.iter().map(|v| v.map(|f| f.to_bits()))),
+            Column::Str(col) => mark(col.iter()),
+            Column::Categorical(col) => mark(col.codes.iter()),
+            Column::Bool(col) => mark(col.iter()),
+            Column::Decimal(col) => {
+                mark(col.0.iter().map(|v| v.map(|d| d.normalize().serialize())))
+            }
+            Column::Date(col) => mark(col.0.iter()),
+            Column::DateTime(col) => mark(col.0.iter()),
+        }
+    }
+
+    /// Renders the value at `idx` for reports; `None` for nulls or out-of-range rows.
+    pub fn format_value(&self, idx: usize) -> Option<String> {
+        match self {
+            Column::Int(col) => col.get(idx).map(|v| v.to_string()),
+            Column::Float(col) => col.get(idx).map(|v| v.to_string()),
+            Column::Str(col) => col.get(idx).map(String::from),
+            Column::Categorical(col) => col.get(idx).map(String::from),
+            Column::Bool(col) => col.get(idx).map(|v| v.to_string()),
+            Column::Decimal(col) => col.0.get(idx)?.map(|v| v.to_string()),
+            Column::Date(col) => col.0.get(idx)?.map(|v| v.to_string()),
+            Column::DateTime(col) => col.0.get(idx)?.map(|v| v.to_rfc3339()),
+        }
+    }
+
+    /// The value at `idx` as a key for equality checks such as uniqueness;
+    /// `None` for nulls or out-of-range rows. Unlike `format_value`, datetimes
+    /// are rendered in UTC and decimals without trailing zeros, so equal
+    /// instants written with different offsets, and equal decimals written
+    /// with different scales, get the same key, as they do in `duplicated`.
+    pub fn key_value(&self, idx: usize) -> Option<String> {
+        match self {
+            Column::Decimal(col) => col.0.get(idx)?.map(|v| v.normalize().to_string()),
+            Column::DateTime(col) => col.0.get(idx)?.map(|v| v.to_utc().to_rfc3339()),
+            _ => self.format_value(idx),
+        }
+    }
+
+    /// Builds a new column from the rows at `indices`, in the given order.
+    pub fn take(&self, indices: &[usize]) -> Column {
+        fn pick<T: Clone>(values: &[Option<T>], indices: &[usize]) -> Vec<Option<T>> {
+            indices.iter().map(|&i| values[i].clone()).collect()
+        }
+        match self {
+            Column::Int(col) => Column::Int(col.take(indices)),
+            Column::Float(col) => Column::Float(col.take(indices)),
+            Column::Str(col) => Column::Str(col.take(indices)),
+            Column::Categorical(col) => Column::Categorical(CategoricalColumn {
+                codes: pick(&col.codes, indices),
+                dictionary: col.dictionary.clone(),
+            }),
+            Column::Bool(col) => Column::Bool(col.take(indices)),
+            Column::Decimal(col) => Column::Decimal(DecimalColumn(pick(&col.0, indices))),
+            Column::Date(col) => Column::Date(DateColumn(pick(&col.0, indices))),
+            Column::DateTime(col) => Column::DateTime(DateTimeColumn(pick(&col.0, indices))),
+        }
+    }
+
+    /// Copies the rows in `rows` into a new column.
+    pub fn slice(&self, rows: Range<usize>) -> Column {
+        match self {
+            Column::Int(col) => Column::Int(col.slice(rows)),
+            Column::Float(col) => Column::Float(col.slice(rows)),
+            Column::Str(col) => Column::Str(col.slice(rows)),
+            Column::Categorical(col) => Column::Categorical(CategoricalColumn {
+                codes: col.codes[rows].to_vec(),
+                dictionary: col.dictionary.clone(),
+            }),
+            Column::Bool(col) => Column::Bool(col.slice(rows)),
+            Column::Decimal(col) => Column::Decimal(DecimalColumn(col.0[rows].to_vec())),
+            Column::Date(col) => Column::Date(DateColumn(col.0[rows].to_vec())),
+            Column::DateTime(col) => Column::DateTime(DateTimeColumn(col.0[rows].to_vec())),
+        }
+    }
+
+    pub fn is_in(&self, other: &InSetValues) -> Mask {
         match (self, other) {
-            (Column::Int(col), InSetValues::IntSet(set)) => col
-                .0
-                .iter()
-                .map(|opt| opt.map(|v| set.contains(&v)))
-                .collect(),
-            (Column::Float(col), InSetValues::FloatSet(set)) => col
-                .0
-                .iter()
-                .map(|opt| opt.map(|v| set.contains(&v)))
-                .collect(),
-            (Column::Str(col), InSetValues::StrSet(set)) => col
-                .0
-                .iter()
-                .map(|opt| opt.as_ref().map(|v| set.contains(v)))
-                .collect(),
-            _ => vec![None; self.len()],
+            (Column::Int(col), InSetValues::IntSet(set)) => col.mask(|v| set.contains(&v)),
+            (Column::Float(col), InSetValues::FloatSet(set)) => col.mask(|v| set.contains(&v)),
+            (Column::Decimal(col), InSetValues::FloatSet(set)) => {
+                let set: Vec<Decimal> = set.iter().filter_map(|v| decimal_from_f64(*v)).collect();
+                col.0
+                    .iter()
+                    .map(|opt| opt.map(|v| set.contains(&v)))
+                    .collect()
+            }
+            (Column::Str(col), InSetValues::StrSet(set)) => {
+                col.mask(|v| set.iter().any(|s| s == v))
+            }
+            (Column::Categorical(col), InSetValues::StrSet(set)) => {
+                let set: HashSet<&str> = set.iter().map(String::as_str).collect();
+                col.mask_dictionary(|v| set.contains(v))
+            }
+            _ => Mask::nulls(self.len()),
         }
     }
 
@@ -173,6 +712,7 @@ impl Column {
         match self {
             Column::Int(col) => col.sum().map(|v| v as f64),
             Column::Float(col) => col.sum(),
+            Column::Decimal(col) => col.sum().and_then(|v| v.to_f64()),
             _ => None,
         }
     }
@@ -181,6 +721,7 @@ impl Column {
         match self {
             Column::Int(col) => col.mean(),
             Column::Float(col) => col.mean(),
+            Column::Decimal(col) => col.mean(),
             _ => None,
         }
     }
@@ -189,6 +730,7 @@ impl Column {
         match self {
             Column::Int(col) => col.min().map(|v| v as f64),
             Column::Float(col) => col.min(),
+            Column::Decimal(col) => col.min().and_then(|v| v.to_f64()),
             _ => None,
         }
     }
@@ -197,6 +739,7 @@ impl Column {
         match self {
             Column::Int(col) => col.max().map(|v| v as f64),
             Column::Float(col) => col.max(),
+            Column::Decimal(col) => col.max().and_then(|v| v.to_f64()),
             _ => None,
         }
     }
@@ -205,6 +748,7 @@ impl Column {
         match self {
             Column::Int(col) => col.std(),
             Column::Float(col) => col.std(),
+            Column::Decimal(col) => col.std(),
             _ => None,
         }
     }
@@ -213,96 +757,278 @@ impl Column {
         match self {
             Column::Int(col) => col.median(),
             Column::Float(col) => col.median(),
+            Column::Decimal(col) => col.median(),
             _ => None,
         }
     }
 
-    pub fn gt(&self, compare: f64) -> Vec<Option<bool>> {
+    pub fn gt(&self, compare: f64) -> Mask {
         match self {
             Column::Int(col) => col.gt(compare),
             Column::Float(col) => col.gt(compare),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.gt(compare),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn ge(&self, compare: f64) -> Vec<Option<bool>> {
+    pub fn ge(&self, compare: f64) -> Mask {
         match self {
             Column::Int(col) => col.ge(compare),
             Column::Float(col) => col.ge(compare),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.ge(compare),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn lt(&self, compare: f64) -> Vec<Option<bool>> {
+    pub fn lt(&self, compare: f64) -> Mask {
         match self {
             Column::Int(col) => col.lt(compare),
             Column::Float(col) => col.lt(compare),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.lt(compare),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn le(&self, compare: f64) -> Vec<Option<bool>> {
+    pub fn le(&self, compare: f64) -> Mask {
         match self {
             Column::Int(col) => col.le(compare),
             Column::Float(col) => col.le(compare),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.le(compare),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn equal(&self, compare: f64) -> Vec<Option<bool>> {
+    pub fn equal(&self, compare: f64) -> Mask {
         match self {
             Column::Int(col) => col.equal(compare),
             Column::Float(col) => col.equal(compare),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.equal(compare),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn equal_str(&self, compare: &str) -> Vec<Option<bool>> {
+    pub fn equal_str(&self, compare: &str) -> Mask {
         match self {
             Column::Str(col) => col.equal(compare),
+            Column::Categorical(col) => col.equal(compare),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Row-wise ordering of this column against `other`. Int, Float and Decimal
+    /// columns compare numerically with each other (exactly unless a Float is
+    /// involved); `None` for nulls and incomparable types.
+    pub fn compare(&self, other: &Column) -> Vec<Option<Ordering>> {
+        fn zip<A, B>(
+            a: impl Iterator<Item = Option<A>>,
+            b: impl Iterator<Item = Option<B>>,
+            cmp: impl Fn(A, B) -> Option<Ordering>,
+        ) -> Vec<Option<Ordering>> {
+            a.zip(b)
+                .map(|(x, y)| match (x, y) {
+                    (Some(x), Some(y)) => cmp(x, y),
+                    _ => None,
+                })
+                .collect()
+        }
+        match (self, other) {
+            (Column::Int(a), Column::Int(b)) => zip(a.iter(), b.iter(), |x, y| Some(x.cmp(&y))),
+            (Column::Int(a), Column::Float(b)) => {
+                zip(a.iter(), b.iter(), |x, y| (x as f64).partial_cmp(&y))
+            }
+            (Column::Float(a), Column::Int(b)) => {
+                zip(a.iter(), b.iter(), |x, y| x.partial_cmp(&(y as f64)))
+            }
+            (Column::Float(a), Column::Float(b)) => {
+                zip(a.iter(), b.iter(), |x, y| x.partial_cmp(&y))
+            }
+            (Column::Decimal(a), Column::Decimal(b)) => {
+                zip(a.0.iter().copied(), b.0.iter().copied(), |x, y| {
+                    Some(x.cmp(&y))
+                })
+            }
+            (Column::Decimal(a), Column::Int(b)) => zip(a.0.iter().copied(), b.iter(), |x, y| {
+                Some(x.cmp(&Decimal::from(y)))
+            }),
+            (Column::Int(a), Column::Decimal(b)) => zip(a.iter(), b.0.iter().copied(), |x, y| {
+                Some(Decimal::from(x).cmp(&y))
+            }),
+            (Column::Decimal(a), Column::Float(b)) => zip(a.0.iter().copied(), b.iter(), |x, y| {
+                x.to_f64()?.partial_cmp(&y)
+            }),
+            (Column::Float(a), Column::Decimal(b)) => zip(a.iter(), b.0.iter().copied(), |x, y| {
+                x.partial_cmp(&y.to_f64()?)
+            }),
+            (Column::Str(a), Column::Str(b)) => zip(a.iter(), b.iter(), |x, y| Some(x.cmp(y))),
+            (Column::Categorical(_), Column::Str(_) | Column::Categorical(_))
+            | (Column::Str(_), Column::Categorical(_)) => (0..self.len())
+                .map(|i| {
+                    let (Some(x), Some(y)) = (self.str_value(i), other.str_value(i)) else {
+                        return None;
+                    };
+                    Some(x.cmp(y))
+                })
+                .collect(),
+            (Column::Bool(a), Column::Bool(b)) => zip(a.iter(), b.iter(), |x, y| Some(x.cmp(&y))),
+            (Column::Date(a), Column::Date(b)) => {
+                zip(a.0.iter().copied(), b.0.iter().copied(), |x, y| {
+                    Some(x.cmp(&y))
+                })
+            }
+            (Column::DateTime(a), Column::DateTime(b)) => {
+                zip(a.0.iter().copied(), b.0.iter().copied(), |x, y| {
+                    Some(x.cmp(&y))
+                })
+            }
             _ => vec![None; self.len()],
         }
     }
 
-    pub fn between(&self, lower: f64, upper: f64) -> Vec<Option<bool>> {
+    pub fn between(&self, lower: f64, upper: f64) -> Mask {
         match self {
             Column::Int(col) => col.between(lower, upper),
             Column::Float(col) => col.between(lower, upper),
-            _ => vec![None; self.len()],
+            Column::Decimal(col) => col.between(lower, upper),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn contains(&self, pat: &str) -> Vec<Option<bool>> {
+    pub fn contains(&self, pat: &str) -> Mask {
         match self {
             Column::Str(col) => col.contains(pat),
-            _ => vec![None; self.len()],
+            Column::Categorical(col) => col.contains(pat),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn starts_with(&self, pat: &str) -> Vec<Option<bool>> {
+    pub fn starts_with(&self, pat: &str) -> Mask {
         match self {
             Column::Str(col) => col.starts_with(pat),
-            _ => vec![None; self.len()],
+            Column::Categorical(col) => col.starts_with(pat),
+            _ => Mask::nulls(self.len()),
         }
     }
 
-    pub fn ends_with(&self, pat: &str) -> Vec<Option<bool>> {
+    pub fn ends_with(&self, pat: &str) -> Mask {
         match self {
             Column::Str(col) => col.ends_with(pat),
+            Column::Categorical(col) => col.ends_with(pat),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Compiles `pat` and matches every value against it; use `is_match` to
+    /// reuse a regex compiled up front.
+    pub fn matches_regex(&self, pat: &str) -> Result<Mask, regex::Error> {
+        Ok(self.is_match(&Regex::new(pat)?))
+    }
+
+    pub fn is_match(&self, re: &Regex) -> Mask {
+        match self {
+            Column::Str(col) => col.is_match(re),
+            Column::Categorical(col) => col.is_match(re),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Row-wise `value <= t`; Date columns compare against the calendar date
+    /// of `t` in its own offset.
+    pub fn le_datetime(&self, t: DateTime<FixedOffset>) -> Mask {
+        match self {
+            Column::Date(col) => col.le(t.date_naive()),
+            Column::DateTime(col) => col.le(t),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Row-wise `value >= t`; Date columns compare against the calendar date
+    /// of `t` in its own offset.
+    pub fn ge_datetime(&self, t: DateTime<FixedOffset>) -> Mask {
+        match self {
+            Column::Date(col) => col.ge(t.date_naive()),
+            Column::DateTime(col) => col.ge(t),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Row-wise `value <= now`, for Date columns today's date in UTC.
+    pub fn not_in_future(&self) -> Mask {
+        self.le_datetime(Utc::now().fixed_offset())
+    }
+
+    pub fn year(&self) -> Vec<Option<i32>> {
+        match self {
+            Column::Date(col) => col.year(),
+            Column::DateTime(col) => col.year(),
+            _ => vec![None; self.len()],
+        }
+    }
+
+    pub fn month(&self) -> Vec<Option<u32>> {
+        match self {
+            Column::Date(col) => col.month(),
+            Column::DateTime(col) => col.month(),
             _ => vec![None; self.len()],
         }
     }
 
-    pub fn matches_regex(&self, pat: &str) -> Vec<Option<bool>> {
+    pub fn day(&self) -> Vec<Option<u32>> {
         match self {
-            Column::Str(col) => col.matches_regex(pat),
+            Column::Date(col) => col.day(),
+            Column::DateTime(col) => col.day(),
             _ => vec![None; self.len()],
         }
     }
 
+    pub fn between_dates(&self, min: NaiveDate, max: NaiveDate) -> Mask {
+        match self {
+            Column::Date(col) => col.between_dates(min, max),
+            Column::DateTime(col) => col.between_dates(min, max),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    pub fn is_weekend(&self) -> Mask {
+        match self {
+            Column::Date(col) => col.is_weekend(),
+            Column::DateTime(col) => col.is_weekend(),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// Number of decimal places each value needs, ignoring trailing zeros
+    /// (`1.50` needs 1). Int values need none.
+    pub fn scale(&self) -> Vec<Option<u32>> {
+        match self {
+            Column::Int(col) => col.map(|_| 0),
+            Column::Decimal(col) => col.scale(),
+            _ => vec![None; self.len()],
+        }
+    }
+
+    /// Row-wise check that the value fits SQL `NUMERIC(precision, scale)`:
+    /// at most `scale` decimal places and `precision - scale` integer digits.
+    pub fn fits_precision(&self, precision: u32, scale: u32) -> Mask {
+        match self {
+            Column::Int(col) => col.mask(|v| fits_precision(Decimal::from(v), precision, scale)),
+            Column::Decimal(col) => col.fits_precision(precision, scale),
+            _ => Mask::nulls(self.len()),
+        }
+    }
+
+    /// The value at `idx` of a Str or Categorical column.
+    fn str_value(&self, idx: usize) -> Option<&str> {
+        match self {
+            Column::Str(col) => col.get(idx),
+            Column::Categorical(col) => col.get(idx),
+            _ => None,
+        }
+    }
+
     pub fn str_length(&self) -> Vec<Option<usize>> {
         match self {
             Column::Str(col) => col.length(),
+            Column::Categorical(col) => col.length(),
             _ => vec![None; self.len()],
         }
     }
@@ -0,0 +1,192 @@
+//! Row-wise outcomes of column checks.
+
+use std::fmt;
+
+use crate::dataset::bitmap::Bitmap;
+
+/// One three-valued outcome per row: true, false, or null when the row's
+/// value is null or the check doesn't apply to it. Combining masks follows
+/// SQL's logic, so `false AND null` is false and `true OR null` is true.
+///
+/// Stored as two bitmaps; a row is true when its bit is set in both.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mask {
+    /// Cleared wherever `validity` is, so it alone marks the true rows.
+    values: Bitmap,
+    validity: Bitmap,
+}
+
+impl Mask {
+    /// Panics if the bitmaps differ in length. Values under null rows are
+    /// ignored.
+    pub fn new(values: Bitmap, validity: Bitmap) -> Self {
+        Mask {
+            values: values.and(&validity),
+            validity,
+        }
+    }
+
+    /// `len` rows, all true or all false.
+    pub fn filled(len: usize, value: bool) -> Self {
+        Mask {
+            values: Bitmap::filled(len, value),
+            validity: Bitmap::filled(len, true),
+        }
+    }
+
+    /// `len` null rows.
+    pub fn nulls(len: usize) -> Self {
+        Mask {
+            values: Bitmap::filled(len, false),
+            validity: Bitmap::filled(len, false),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        self.validity.get(idx).then(|| self.values.get(idx))
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, value: Option<bool>) {
+        self.validity.set(idx, value.is_some());
+        self.values.set(idx, value == Some(true));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Option<bool>> + '_ {
+        self.values
+            .iter()
+            .zip(self.validity.iter())
+            .map(|(value, valid)| valid.then_some(value))
+    }
+
+    pub fn to_vec(&self) -> Vec<Option<bool>> {
+        self.iter().collect()
+    }
+
+    /// Bits of the true rows.
+    pub fn values(&self) -> &Bitmap {
+        &self.values
+    }
+
+    /// Bits of the non-null rows.
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    /// Panics if the masks differ in length.
+    pub fn and(&self, other: &Mask) -> Mask {
+        // a row is false when either side is false, true when both are true
+        let falses = self.falses().or(&other.falses());
+        let values = self.values.and(&other.values);
+        Mask {
+            validity: values.or(&falses),
+            values,
+        }
+    }
+
+    /// Panics if the masks differ in length.
+    pub fn or(&self, other: &Mask) -> Mask {
+        // a row is true when either side is true, false when both are false
+        let falses = self.falses().and(&other.falses());
+        let values = self.values.or(&other.values);
+        Mask {
+            validity: values.or(&falses),
+            values,
+        }
+    }
+
+    /// Flips true and false; null rows stay null.
+    pub fn not(&self) -> Mask {
+        Mask {
+            values: self.falses(),
+            validity: self.validity.clone(),
+        }
+    }
+
+    /// Replaces null rows with `value`.
+    pub fn fill_null(&self, value: bool) -> Mask {
+        let values = if value {
+            self.values.or(&self.validity.not())
+        } else {
+            self.values.clone()
+        };
+        Mask {
+            values,
+            validity: Bitmap::filled(self.len(), true),
+        }
+    }
+
+    pub fn count_true(&self) -> usize {
+        self.values.count_ones()
+    }
+
+    pub fn count_false(&self) -> usize {
+        self.validity.count_ones() - self.values.count_ones()
+    }
+
+    pub fn count_null(&self) -> usize {
+        self.len() - self.validity.count_ones()
+    }
+
+    /// Rows that are true, in ascending order.
+    pub fn true_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.values.set_indices()
+    }
+
+    /// Rows that are false or null, i.e. that fail a check, in ascending order.
+    pub fn not_true_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.values.unset_indices()
+    }
+
+    /// Appends the rows of `other`.
+    pub fn extend(&mut self, other: &Mask) {
+        self.values.extend(&other.values);
+        self.validity.extend(&other.validity);
+    }
+
+    fn falses(&self) -> Bitmap {
+        self.validity.and_not(&self.values)
+    }
+}
+
+impl FromIterator<Option<bool>> for Mask {
+    fn from_iter<I: IntoIterator<Item = Option<bool>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut values = Bitmap::with_capacity(iter.size_hint().0);
+        let mut validity = Bitmap::with_capacity(iter.size_hint().0);
+        for value in iter {
+            values.push(value == Some(true));
+            validity.push(value.is_some());
+        }
+        Mask { values, validity }
+    }
+}
+
+impl From<Vec<Option<bool>>> for Mask {
+    fn from(values: Vec<Option<bool>>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl PartialEq<Vec<Option<bool>>> for Mask {
+    fn eq(&self, other: &Vec<Option<bool>>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter().copied())
+    }
+}
+
+/// Lists the rows, like the `Vec<Option<bool>>` the mask stands for.
+impl fmt::Debug for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -1,19 +1,58 @@
+pub mod bitmap;
 pub mod column;
+pub mod mask;
 pub mod ops;
 pub mod schema;
+pub mod stats;
 
-pub use column::{BoolColumn, Column, FloatColumn, InSetValues, IntColumn, StrColumn};
-pub use ops::NumericOps;
-pub use schema::{DataType, Field, Schema};
+pub use bitmap::Bitmap;
+pub use column::{
+    BoolColumn, CategoricalColumn, Column, DateColumn, DateTimeColumn, DecimalColumn, FloatColumn,
+    InSetValues, IntColumn, PrimitiveColumn, StrColumn,
+};
+pub use mask::Mask;
+pub use ops::{DateTimeOps, DecimalOps, NumericOps};
+pub use schema::{DataType, ExtraColumns, Field, Schema};
+pub use stats::ColumnStats;
+
+/// A cell that lenient loading could not read as its column's type and
+/// replaced with null.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coercion {
+    pub row: usize,
+    pub column: String,
+    /// The raw cell, or `None` when the row was too short to have it.
+    pub value: Option<String>,
+    pub expected: DataType,
+}
+
+/// A row lenient loading found with more cells than the header; the extra
+/// cells are dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlongRow {
+    pub row: usize,
+    /// The dropped cells.
+    pub extra: Vec<String>,
+}
 
 pub struct Dataset {
     pub headers: Vec<String>,
     pub columns: Vec<Column>,
+    /// Cells nulled out while loading; reported by `validate` as type-conformance failures.
+    pub coercions: Vec<Coercion>,
+    /// Rows cut short to the header while loading; reported by `validate` as a
+    /// row-length failure.
+    pub overlong_rows: Vec<OverlongRow>,
 }
 
 impl Dataset {
     pub fn new(headers: Vec<String>, columns: Vec<Column>) -> Self {
-        Dataset { headers, columns }
+        Dataset {
+            headers,
+            columns,
+            coercions: Vec::new(),
+            overlong_rows: Vec::new(),
+        }
     }
 
     pub fn get_column_by_name(&self, name: &str) -> Option<&Column> {
@@ -32,6 +71,17 @@ impl Dataset {
         self.headers.iter().position(|h| h == name)
     }
 
+    /// The dataset's columns and their types, in header order.
+    pub fn schema(&self) -> Schema {
+        Schema::new(
+            self.headers
+                .iter()
+                .zip(&self.columns)
+                .map(|(name, column)| Field::new(name.clone(), column.dtype()))
+                .collect(),
+        )
+    }
+
     pub fn shape(&self) -> (usize, usize) {
         let rows_count = self.columns.first().map_or(0, |c| c.len());
         (rows_count, self.columns.len())
@@ -1,5 +1,10 @@
-use super::{FloatColumn, IntColumn, StrColumn};
+use super::{
+    CategoricalColumn, DateColumn, DateTimeColumn, DecimalColumn, FloatColumn, IntColumn, Mask,
+    StrColumn,
+};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Weekday};
 use regex::Regex;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 
 pub trait NumericOps {
     type Item;
@@ -12,19 +17,39 @@ pub trait NumericOps {
 }
 
 pub trait ComparableOps<T> {
-    fn gt(&self, compare: T) -> Vec<Option<bool>>;
-    fn ge(&self, compare: T) -> Vec<Option<bool>>;
-    fn lt(&self, compare: T) -> Vec<Option<bool>>;
-    fn le(&self, compare: T) -> Vec<Option<bool>>;
-    fn equal(&self, compare: T) -> Vec<Option<bool>>;
-    fn between(&self, lower: T, upper: T) -> Vec<Option<bool>>;
+    fn gt(&self, compare: T) -> Mask;
+    fn ge(&self, compare: T) -> Mask;
+    fn lt(&self, compare: T) -> Mask;
+    fn le(&self, compare: T) -> Mask;
+    fn equal(&self, compare: T) -> Mask;
+    fn between(&self, lower: T, upper: T) -> Mask;
+}
+
+/// Calendar accessors for Date and DateTime columns. DateTime values use the
+/// date in their own UTC offset.
+pub trait DateTimeOps {
+    fn year(&self) -> Vec<Option<i32>>;
+    fn month(&self) -> Vec<Option<u32>>;
+    fn day(&self) -> Vec<Option<u32>>;
+    fn between_dates(&self, min: NaiveDate, max: NaiveDate) -> Mask;
+    fn is_weekend(&self) -> Mask;
+}
+
+/// Scale and precision checks for Decimal columns.
+pub trait DecimalOps {
+    /// Decimal places each value needs, ignoring trailing zeros.
+    fn scale(&self) -> Vec<Option<u32>>;
+    /// Whether each value fits SQL `NUMERIC(precision, scale)`.
+    fn fits_precision(&self, precision: u32, scale: u32) -> Mask;
 }
 
 pub trait StringOps {
-    fn contains(&self, pat: &str) -> Vec<Option<bool>>;
-    fn starts_with(&self, pat: &str) -> Vec<Option<bool>>;
-    fn ends_with(&self, pat: &str) -> Vec<Option<bool>>;
-    fn matches_regex(&self, pat: &str) -> Vec<Option<bool>>;
+    fn contains(&self, pat: &str) -> Mask;
+    fn starts_with(&self, pat: &str) -> Mask;
+    fn ends_with(&self, pat: &str) -> Mask;
+    /// Compiles `pat` and matches every value against it.
+    fn matches_regex(&self, pat: &str) -> Result<Mask, regex::Error>;
+    fn is_match(&self, re: &Regex) -> Mask;
     fn length(&self) -> Vec<Option<usize>>;
 }
 
@@ -35,15 +60,16 @@ impl NumericOps for IntColumn {
         if self.not_null_count() == 0 {
             return None;
         }
-        Some(self.0.iter().flatten().sum())
+        // null slots hold zero, so the whole buffer can be summed
+        Some(self.values().iter().sum())
     }
 
     fn min(&self) -> Option<Self::Item> {
-        self.0.iter().filter_map(|v| *v).min()
+        self.valid_values().min()
     }
 
     fn max(&self) -> Option<Self::Item> {
-        self.0.iter().filter_map(|v| *v).max()
+        self.valid_values().max()
     }
 
     fn mean(&self) -> Option<f64> {
@@ -58,17 +84,12 @@ impl NumericOps for IntColumn {
         if count < 2 {
             return None;
         }
-        let sq_sum: f64 = self
-            .0
-            .iter()
-            .filter_map(|v| *v)
-            .map(|v| (v as f64 - mean).powi(2))
-            .sum();
+        let sq_sum: f64 = self.valid_values().map(|v| (v as f64 - mean).powi(2)).sum();
         Some((sq_sum / (count - 1) as f64).sqrt())
     }
 
     fn median(&self) -> Option<f64> {
-        let mut vals: Vec<i64> = self.0.iter().filter_map(|v| *v).collect();
+        let mut vals: Vec<i64> = self.valid_values().collect();
         if vals.is_empty() {
             return None;
         }
@@ -89,15 +110,15 @@ impl NumericOps for FloatColumn {
         if self.not_null_count() == 0 {
             return None;
         }
-        Some(self.0.iter().flatten().sum())
+        Some(self.valid_values().sum())
     }
 
     fn min(&self) -> Option<Self::Item> {
-        self.0.iter().filter_map(|v| *v).reduce(f64::min)
+        self.valid_values().reduce(f64::min)
     }
 
     fn max(&self) -> Option<Self::Item> {
-        self.0.iter().filter_map(|v| *v).reduce(f64::max)
+        self.valid_values().reduce(f64::max)
     }
 
     fn mean(&self) -> Option<f64> {
@@ -112,17 +133,12 @@ impl NumericOps for FloatColumn {
         if count < 2 {
             return None;
         }
-        let sq_sum: f64 = self
-            .0
-            .iter()
-            .filter_map(|v| *v)
-            .map(|v| (v - mean).powi(2))
-            .sum();
+        let sq_sum: f64 = self.valid_values().map(|v| (v - mean).powi(2)).sum();
         Some((sq_sum / (count - 1) as f64).sqrt())
     }
 
     fn median(&self) -> Option<f64> {
-        let mut vals: Vec<f64> = self.0.iter().filter_map(|v| *v).collect();
+        let mut vals: Vec<f64> = self.valid_values().collect();
         if vals.is_empty() {
             return None;
         }
@@ -137,185 +153,465 @@ impl NumericOps for FloatColumn {
 }
 
 impl ComparableOps<i64> for IntColumn {
-    fn gt(&self, compare: i64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x > compare)).collect()
+    fn gt(&self, compare: i64) -> Mask {
+        self.mask(|x| x > compare)
     }
 
-    fn ge(&self, compare: i64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x >= compare)).collect()
+    fn ge(&self, compare: i64) -> Mask {
+        self.mask(|x| x >= compare)
     }
 
-    fn lt(&self, compare: i64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x < compare)).collect()
+    fn lt(&self, compare: i64) -> Mask {
+        self.mask(|x| x < compare)
     }
 
-    fn le(&self, compare: i64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x <= compare)).collect()
+    fn le(&self, compare: i64) -> Mask {
+        self.mask(|x| x <= compare)
     }
 
-    fn equal(&self, compare: i64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x == compare)).collect()
+    fn equal(&self, compare: i64) -> Mask {
+        self.mask(|x| x == compare)
     }
 
-    fn between(&self, lower: i64, upper: i64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| x >= lower && x <= upper))
-            .collect()
+    fn between(&self, lower: i64, upper: i64) -> Mask {
+        self.mask(|x| x >= lower && x <= upper)
     }
 }
 
 impl ComparableOps<f64> for IntColumn {
-    fn gt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) > compare))
-            .collect()
+    fn gt(&self, compare: f64) -> Mask {
+        self.mask(|x| (x as f64) > compare)
     }
 
-    fn ge(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) >= compare))
-            .collect()
+    fn ge(&self, compare: f64) -> Mask {
+        self.mask(|x| (x as f64) >= compare)
     }
 
-    fn lt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) < compare))
-            .collect()
+    fn lt(&self, compare: f64) -> Mask {
+        self.mask(|x| (x as f64) < compare)
     }
 
-    fn le(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) <= compare))
-            .collect()
+    fn le(&self, compare: f64) -> Mask {
+        self.mask(|x| (x as f64) <= compare)
     }
 
-    fn equal(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) == compare))
-            .collect()
+    fn equal(&self, compare: f64) -> Mask {
+        self.mask(|x| (x as f64) == compare)
     }
 
-    fn between(&self, lower: f64, upper: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| (x as f64) >= lower && (x as f64) <= upper))
-            .collect()
+    fn between(&self, lower: f64, upper: f64) -> Mask {
+        self.mask(|x| (x as f64) >= lower && (x as f64) <= upper)
     }
 }
 
 impl ComparableOps<f64> for FloatColumn {
-    fn gt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x > compare)).collect()
+    fn gt(&self, compare: f64) -> Mask {
+        self.mask(|x| x > compare)
     }
 
-    fn ge(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x >= compare)).collect()
+    fn ge(&self, compare: f64) -> Mask {
+        self.mask(|x| x >= compare)
     }
 
-    fn lt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x < compare)).collect()
+    fn lt(&self, compare: f64) -> Mask {
+        self.mask(|x| x < compare)
     }
 
-    fn le(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x <= compare)).collect()
+    fn le(&self, compare: f64) -> Mask {
+        self.mask(|x| x <= compare)
     }
 
-    fn equal(&self, compare: f64) -> Vec<Option<bool>> {
-        self.0.iter().map(|v| v.map(|x| x == compare)).collect()
+    fn equal(&self, compare: f64) -> Mask {
+        self.mask(|x| x == compare)
     }
 
-    fn between(&self, lower: f64, upper: f64) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.map(|x| x >= lower && x <= upper))
-            .collect()
+    fn between(&self, lower: f64, upper: f64) -> Mask {
+        self.mask(|x| x >= lower && x <= upper)
     }
 }
 
-impl ComparableOps<&str> for StrColumn {
-    fn gt(&self, compare: &str) -> Vec<Option<bool>> {
+impl NumericOps for DecimalColumn {
+    type Item = Decimal;
+
+    /// Exact; `None` if the sum overflows `Decimal`.
+    fn sum(&self) -> Option<Self::Item> {
+        if self.not_null_count() == 0 {
+            return None;
+        }
         self.0
             .iter()
-            .map(|v| v.as_ref().map(|s| s.as_str() > compare))
-            .collect()
+            .flatten()
+            .try_fold(Decimal::ZERO, |acc, v| acc.checked_add(*v))
     }
 
-    fn ge(&self, compare: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.as_str() >= compare))
-            .collect()
+    fn min(&self) -> Option<Self::Item> {
+        self.0.iter().flatten().min().copied()
     }
 
-    fn lt(&self, compare: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.as_str() < compare))
-            .collect()
+    fn max(&self) -> Option<Self::Item> {
+        self.0.iter().flatten().max().copied()
     }
 
-    fn le(&self, compare: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.as_str() <= compare))
-            .collect()
+    fn mean(&self) -> Option<f64> {
+        let sum = self.sum()?;
+        let count = Decimal::from(self.not_null_count());
+        sum.checked_div(count)?.to_f64()
     }
 
-    fn equal(&self, compare: &str) -> Vec<Option<bool>> {
-        self.0
+    fn std(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let count = self.not_null_count();
+        if count < 2 {
+            return None;
+        }
+        let sq_sum: f64 = self
+            .0
             .iter()
-            .map(|v| v.as_ref().map(|s| s.as_str() == compare))
-            .collect()
+            .flatten()
+            .filter_map(|v| v.to_f64())
+            .map(|v| (v - mean).powi(2))
+            .sum();
+        Some((sq_sum / (count - 1) as f64).sqrt())
+    }
+
+    fn median(&self) -> Option<f64> {
+        let mut vals: Vec<Decimal> = self.0.iter().flatten().copied().collect();
+        if vals.is_empty() {
+            return None;
+        }
+        vals.sort();
+        let mid = vals.len() / 2;
+        if vals.len().is_multiple_of(2) {
+            vals[mid - 1]
+                .checked_add(vals[mid])?
+                .checked_div(Decimal::TWO)?
+                .to_f64()
+        } else {
+            vals[mid].to_f64()
+        }
+    }
+}
+
+impl ComparableOps<Decimal> for DecimalColumn {
+    fn gt(&self, compare: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x > compare)
+    }
+
+    fn ge(&self, compare: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x >= compare)
+    }
+
+    fn lt(&self, compare: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x < compare)
+    }
+
+    fn le(&self, compare: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x <= compare)
+    }
+
+    fn equal(&self, compare: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x == compare)
+    }
+
+    fn between(&self, lower: Decimal, upper: Decimal) -> Mask {
+        mask_values(&self.0, |x| *x >= lower && *x <= upper)
     }
+}
+
+/// The decimal an `f64` is written as (`0.1` is exactly 0.1), or `None` if
+/// that doesn't fit a `Decimal`.
+pub(crate) fn decimal_from_f64(value: f64) -> Option<Decimal> {
+    Decimal::from_str_exact(&value.to_string()).ok()
+}
+
+/// Bounds are converted with `decimal_from_f64` and compared exactly; bounds
+/// that don't fit a `Decimal` fall back to comparing as `f64`.
+impl ComparableOps<f64> for DecimalColumn {
+    fn gt(&self, compare: f64) -> Mask {
+        match decimal_from_f64(compare) {
+            Some(compare) => self.gt(compare),
+            None => self.approx(|x| x > compare),
+        }
+    }
+
+    fn ge(&self, compare: f64) -> Mask {
+        match decimal_from_f64(compare) {
+            Some(compare) => self.ge(compare),
+            None => self.approx(|x| x >= compare),
+        }
+    }
+
+    fn lt(&self, compare: f64) -> Mask {
+        match decimal_from_f64(compare) {
+            Some(compare) => self.lt(compare),
+            None => self.approx(|x| x < compare),
+        }
+    }
+
+    fn le(&self, compare: f64) -> Mask {
+        match decimal_from_f64(compare) {
+            Some(compare) => self.le(compare),
+            None => self.approx(|x| x <= compare),
+        }
+    }
+
+    fn equal(&self, compare: f64) -> Mask {
+        match decimal_from_f64(compare) {
+            Some(compare) => self.equal(compare),
+            None => self.approx(|x| x == compare),
+        }
+    }
+
+    fn between(&self, lower: f64, upper: f64) -> Mask {
+        match (decimal_from_f64(lower), decimal_from_f64(upper)) {
+            (Some(lower), Some(upper)) => self.between(lower, upper),
+            _ => self.approx(|x| x >= lower && x <= upper),
+        }
+    }
+}
 
-    fn between(&self, lower: &str, upper: &str) -> Vec<Option<bool>> {
+impl DecimalColumn {
+    fn approx(&self, f: impl Fn(f64) -> bool) -> Mask {
         self.0
             .iter()
-            .map(|v| {
-                v.as_ref()
-                    .map(|s| s.as_str() >= lower && s.as_str() <= upper)
-            })
+            .map(|v| v.and_then(|x| x.to_f64()).map(&f))
             .collect()
     }
 }
 
+pub(crate) fn fits_precision(value: Decimal, precision: u32, scale: u32) -> bool {
+    let value = value.normalize();
+    let integer_digits = value
+        .abs()
+        .trunc()
+        .mantissa()
+        .checked_ilog10()
+        .map_or(0, |log| log + 1);
+    value.scale() <= scale && integer_digits <= precision.saturating_sub(scale)
+}
+
+impl DecimalOps for DecimalColumn {
+    fn scale(&self) -> Vec<Option<u32>> {
+        map_values(&self.0, |x| x.normalize().scale())
+    }
+
+    fn fits_precision(&self, precision: u32, scale: u32) -> Mask {
+        mask_values(&self.0, |x| fits_precision(*x, precision, scale))
+    }
+}
+
+impl ComparableOps<&str> for StrColumn {
+    fn gt(&self, compare: &str) -> Mask {
+        self.mask(|s| s > compare)
+    }
+
+    fn ge(&self, compare: &str) -> Mask {
+        self.mask(|s| s >= compare)
+    }
+
+    fn lt(&self, compare: &str) -> Mask {
+        self.mask(|s| s < compare)
+    }
+
+    fn le(&self, compare: &str) -> Mask {
+        self.mask(|s| s <= compare)
+    }
+
+    fn equal(&self, compare: &str) -> Mask {
+        // rows of a different length never need their bytes read
+        self.mask_spans(|data, span| span.len() == compare.len() && &data[span] == compare)
+    }
+
+    fn between(&self, lower: &str, upper: &str) -> Mask {
+        self.mask(|s| s >= lower && s <= upper)
+    }
+}
+
 impl StringOps for StrColumn {
-    fn contains(&self, pat: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.contains(pat)))
-            .collect()
+    fn contains(&self, pat: &str) -> Mask {
+        self.mask(|s| s.contains(pat))
     }
 
-    fn starts_with(&self, pat: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.starts_with(pat)))
-            .collect()
+    fn starts_with(&self, pat: &str) -> Mask {
+        self.mask(|s| s.starts_with(pat))
     }
 
-    fn ends_with(&self, pat: &str) -> Vec<Option<bool>> {
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| s.ends_with(pat)))
-            .collect()
+    fn ends_with(&self, pat: &str) -> Mask {
+        self.mask(|s| s.ends_with(pat))
     }
 
-    fn matches_regex(&self, pat: &str) -> Vec<Option<bool>> {
-        let re = Regex::new(pat).expect("invalid regex pattern");
-        self.0
-            .iter()
-            .map(|v| v.as_ref().map(|s| re.is_match(s)))
-            .collect()
+    fn matches_regex(&self, pat: &str) -> Result<Mask, regex::Error> {
+        Ok(self.is_match(&Regex::new(pat)?))
+    }
+
+    fn is_match(&self, re: &Regex) -> Mask {
+        self.mask(|s| re.is_match(s))
+    }
+
+    fn length(&self) -> Vec<Option<usize>> {
+        self.lengths()
+    }
+}
+
+impl ComparableOps<&str> for CategoricalColumn {
+    fn gt(&self, compare: &str) -> Mask {
+        self.mask_dictionary(|s| s > compare)
+    }
+
+    fn ge(&self, compare: &str) -> Mask {
+        self.mask_dictionary(|s| s >= compare)
+    }
+
+    fn lt(&self, compare: &str) -> Mask {
+        self.mask_dictionary(|s| s < compare)
+    }
+
+    fn le(&self, compare: &str) -> Mask {
+        self.mask_dictionary(|s| s <= compare)
+    }
+
+    fn equal(&self, compare: &str) -> Mask {
+        self.mask_dictionary(|s| s == compare)
+    }
+
+    fn between(&self, lower: &str, upper: &str) -> Mask {
+        self.mask_dictionary(|s| s >= lower && s <= upper)
+    }
+}
+
+impl StringOps for CategoricalColumn {
+    fn contains(&self, pat: &str) -> Mask {
+        self.mask_dictionary(|s| s.contains(pat))
+    }
+
+    fn starts_with(&self, pat: &str) -> Mask {
+        self.mask_dictionary(|s| s.starts_with(pat))
+    }
+
+    fn ends_with(&self, pat: &str) -> Mask {
+        self.mask_dictionary(|s| s.ends_with(pat))
+    }
+
+    fn matches_regex(&self, pat: &str) -> Result<Mask, regex::Error> {
+        Ok(self.is_match(&Regex::new(pat)?))
+    }
+
+    fn is_match(&self, re: &Regex) -> Mask {
+        self.mask_dictionary(|s| re.is_match(s))
     }
 
     fn length(&self) -> Vec<Option<usize>> {
-        self.0.iter().map(|v| v.as_ref().map(|s| s.len())).collect()
+        self.map_dictionary(str::len)
+    }
+}
+
+fn map_values<T, R>(values: &[Option<T>], f: impl Fn(&T) -> R) -> Vec<Option<R>> {
+    values.iter().map(|v| v.as_ref().map(&f)).collect()
+}
+
+fn mask_values<T>(values: &[Option<T>], f: impl Fn(&T) -> bool) -> Mask {
+    values.iter().map(|v| v.as_ref().map(&f)).collect()
+}
+
+impl ComparableOps<NaiveDate> for DateColumn {
+    fn gt(&self, compare: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d > compare)
+    }
+
+    fn ge(&self, compare: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d >= compare)
+    }
+
+    fn lt(&self, compare: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d < compare)
+    }
+
+    fn le(&self, compare: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d <= compare)
+    }
+
+    fn equal(&self, compare: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d == compare)
+    }
+
+    fn between(&self, lower: NaiveDate, upper: NaiveDate) -> Mask {
+        mask_values(&self.0, |d| *d >= lower && *d <= upper)
+    }
+}
+
+impl ComparableOps<DateTime<FixedOffset>> for DateTimeColumn {
+    fn gt(&self, compare: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t > compare)
+    }
+
+    fn ge(&self, compare: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t >= compare)
+    }
+
+    fn lt(&self, compare: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t < compare)
+    }
+
+    fn le(&self, compare: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t <= compare)
+    }
+
+    fn equal(&self, compare: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t == compare)
+    }
+
+    fn between(&self, lower: DateTime<FixedOffset>, upper: DateTime<FixedOffset>) -> Mask {
+        mask_values(&self.0, |t| *t >= lower && *t <= upper)
+    }
+}
+
+fn is_weekend(weekday: Weekday) -> bool {
+    matches!(weekday, Weekday::Sat | Weekday::Sun)
+}
+
+impl DateTimeOps for DateColumn {
+    fn year(&self) -> Vec<Option<i32>> {
+        map_values(&self.0, |d| d.year())
+    }
+
+    fn month(&self) -> Vec<Option<u32>> {
+        map_values(&self.0, |d| d.month())
+    }
+
+    fn day(&self) -> Vec<Option<u32>> {
+        map_values(&self.0, |d| d.day())
+    }
+
+    fn between_dates(&self, min: NaiveDate, max: NaiveDate) -> Mask {
+        self.between(min, max)
+    }
+
+    fn is_weekend(&self) -> Mask {
+        mask_values(&self.0, |d| is_weekend(d.weekday()))
+    }
+}
+
+impl DateTimeOps for DateTimeColumn {
+    fn year(&self) -> Vec<Option<i32>> {
+        map_values(&self.0, |t| t.year())
+    }
+
+    fn month(&self) -> Vec<Option<u32>> {
+        map_values(&self.0, |t| t.month())
+    }
+
+    fn day(&self) -> Vec<Option<u32>> {
+        map_values(&self.0, |t| t.day())
+    }
+
+    fn between_dates(&self, min: NaiveDate, max: NaiveDate) -> Mask {
+        mask_values(&self.0, |t| {
+            let date = t.date_naive();
+            date >= min && date <= max
+        })
+    }
+
+    fn is_weekend(&self) -> Mask {
+        mask_values(&self.0, |t| is_weekend(t.weekday()))
     }
 }
@@ -1,3 +1,9 @@
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
 pub struct Field {
     pub name: String,
     pub dtype: DataType,
@@ -12,20 +18,80 @@ impl Field {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum DataType {
+    #[cfg_attr(feature = "serde", serde(alias = "integer"))]
     Int,
+    #[cfg_attr(feature = "serde", serde(alias = "string"))]
     Str,
     Float,
+    /// Strings stored as codes into a dictionary of distinct values; for
+    /// low-cardinality columns such as country or status.
+    #[cfg_attr(feature = "serde", serde(alias = "category"))]
+    Categorical,
+    #[cfg_attr(feature = "serde", serde(alias = "boolean"))]
     Bool,
+    /// Exact fixed-point numbers, for amounts that must not be rounded.
+    #[cfg_attr(feature = "serde", serde(alias = "numeric"))]
+    Decimal,
+    Date,
+    /// A point in time with a UTC offset; values without one get the loader's
+    /// default timezone.
+    #[cfg_attr(feature = "serde", serde(alias = "timestamp"))]
+    DateTime,
 }
 
+/// What the CSV loader does with file columns that have no schema field.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ExtraColumns {
+    /// Drop them from the loaded dataset.
+    #[default]
+    Ignore,
+    /// Load them as Str columns after the schema fields.
+    KeepAsStr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
 pub struct Schema {
     pub fields: Vec<Field>,
+    /// Require the file's leading columns to be exactly the schema fields, in order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordered: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra_columns: ExtraColumns,
 }
 
 impl Schema {
     pub fn new(fields: Vec<Field>) -> Self {
-        Schema { fields }
+        Schema {
+            fields,
+            ordered: false,
+            extra_columns: ExtraColumns::default(),
+        }
+    }
+
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    pub fn extra_columns(mut self, extra_columns: ExtraColumns) -> Self {
+        self.extra_columns = extra_columns;
+        self
     }
 }
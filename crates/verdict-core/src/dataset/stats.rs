@@ -0,0 +1,114 @@
+use rust_decimal::prelude::ToPrimitive;
+
+use super::Column;
+
+/// Summary statistics of a column that can be built chunk by chunk and merged.
+///
+/// `count` and `null_count` cover every column type; `sum`, `min`, `max`,
+/// `mean` and `std` are only tracked for Int, Float and Decimal columns and
+/// agree with `Column::sum`/`min`/`max`/`mean`/`std` on the concatenated data
+/// (up to floating-point rounding for `mean` and `std`, and for the `sum` of
+/// Decimal columns, which is tracked as `f64`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnStats {
+    /// Number of rows seen, including nulls.
+    pub count: usize,
+    pub null_count: usize,
+    numeric: Option<Moments>,
+}
+
+/// Running moments of the non-null numeric values (Chan et al. parallel update).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Moments {
+    n: usize,
+    sum: f64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn of(values: impl Iterator<Item = f64>) -> Option<Moments> {
+        values.fold(None, |acc: Option<Moments>, v| {
+            let single = Moments {
+                n: 1,
+                sum: v,
+                min: v,
+                max: v,
+                mean: v,
+                m2: 0.0,
+            };
+            Some(match acc {
+                Some(acc) => acc.merge(&single),
+                None => single,
+            })
+        })
+    }
+
+    fn merge(&self, other: &Moments) -> Moments {
+        let n = self.n + other.n;
+        let delta = other.mean - self.mean;
+        Moments {
+            n,
+            sum: self.sum + other.sum,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+            mean: self.mean + delta * other.n as f64 / n as f64,
+            m2: self.m2 + other.m2 + delta * delta * (self.n * other.n) as f64 / n as f64,
+        }
+    }
+}
+
+impl ColumnStats {
+    pub fn from_column(column: &Column) -> Self {
+        let numeric = match column {
+            Column::Int(col) => Moments::of(col.valid_values().map(|v| v as f64)),
+            Column::Float(col) => Moments::of(col.valid_values()),
+            Column::Decimal(col) => Moments::of(col.0.iter().flatten().filter_map(|v| v.to_f64())),
+            _ => None,
+        };
+        ColumnStats {
+            count: column.len(),
+            null_count: column.null_count(),
+            numeric,
+        }
+    }
+
+    /// Folds in the statistics of more rows of the same column.
+    pub fn merge(&mut self, other: &ColumnStats) {
+        self.count += other.count;
+        self.null_count += other.null_count;
+        self.numeric = match (self.numeric, other.numeric) {
+            (Some(a), Some(b)) => Some(a.merge(&b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    pub fn not_null_count(&self) -> usize {
+        self.count - self.null_count
+    }
+
+    pub fn sum(&self) -> Option<f64> {
+        self.numeric.map(|m| m.sum)
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.numeric.map(|m| m.min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.numeric.map(|m| m.max)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        self.numeric.map(|m| m.mean)
+    }
+
+    /// Sample standard deviation; `None` with fewer than two values.
+    pub fn std(&self) -> Option<f64> {
+        self.numeric
+            .filter(|m| m.n >= 2)
+            .map(|m| (m.m2 / (m.n - 1) as f64).sqrt())
+    }
+}
@@ -2,12 +2,56 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ValidationError {
-    #[error("Failed to validate column '{column}' for constraint '{constraint}'")]
-    ColumnValidationError { column: String, constraint: String },
-
     #[error("Column '{name}' not found in dataset")]
     ColumnNotFound { name: String },
 
-    #[error("Unknown constraint '{name}'")]
-    UnknownConstraint { name: String },
+    #[error("Invalid regex '{pattern}' in rules[{index}] (column '{column}'): {message}")]
+    InvalidRegex {
+        index: usize,
+        column: String,
+        pattern: String,
+        message: String,
+    },
+
+    #[error(
+        "Incompatible type in rules[{index}] (column '{column}'): {constraint} requires {expected}, found {found}"
+    )]
+    IncompatibleType {
+        index: usize,
+        column: String,
+        constraint: String,
+        expected: String,
+        found: String,
+    },
+
+    #[error(
+        "Misplaced constraint in rules[{index}] (column '{column}'): {constraint} compares rows or columns and can't be used {place}"
+    )]
+    NotComposable {
+        index: usize,
+        column: String,
+        constraint: String,
+        /// e.g. `"as a condition"`.
+        place: String,
+    },
+
+    #[error(
+        "Misplaced constraint in rules[{index}] (column '{column}'): {constraint} checks a whole column and can't be used {place}"
+    )]
+    MisplacedAggregate {
+        index: usize,
+        column: String,
+        constraint: String,
+        /// e.g. `"as a condition"`.
+        place: String,
+    },
+
+    #[error(
+        "Invalid tolerance in rules[{index}] (column '{column}'): mostly must be between 0.0 and 1.0, got {fraction}"
+    )]
+    InvalidTolerance {
+        index: usize,
+        column: String,
+        fraction: f64,
+    },
 }
@@ -1,6 +1,10 @@
 pub mod dataset;
 pub mod errors;
+pub mod report;
 pub mod rules;
 
 #[cfg(feature = "csv")]
 pub mod csv_loader;
+
+#[cfg(feature = "json")]
+pub mod suite;
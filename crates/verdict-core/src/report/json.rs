@@ -0,0 +1,95 @@
+//! JSON report output.
+//!
+//! The document layout is versioned by `schema_version` and only changes in
+//! backwards-compatible ways (new fields) without a version bump:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "verdict": "passed" | "warning" | "failed",
+//!   "shape": { "rows": 4, "columns": 5 },
+//!   "elapsed_ms": 0.12,
+//!   "summary": {
+//!     "rules": 3, "load_findings": 0,
+//!     "passed": 2, "failed": 1, "warnings": 0, "info": 0
+//!   },
+//!   "results": [
+//!     {
+//!       "column": "age",
+//!       "constraint": "> 0",
+//!       "status": "pass" | "fail" | "warn" | "info",
+//!       "passed": false,
+//!       "severity": "error" | "warning" | "info",
+//!       "failed_count": 1,
+//!       "evaluated_count": 4,
+//!       "failed_ratio": 0.25,
+//!       "tolerance": null | { "mostly": 0.95 } | { "max_failures": 10 },
+//!       "error": "values not greater than 0" | null,
+//!       "failed_rows": [2],
+//!       "sample_values": ["-1"],
+//!       "observed": null | 41.5
+//!     }
+//!   ]
+//! }
+//! ```
+
+use serde_json::{Value, json};
+
+use super::Report;
+use crate::rules::{Tolerance, ValidationResult, Verdict};
+
+/// Version of the JSON report layout.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+impl Report {
+    /// The report as a JSON value following the documented schema.
+    pub fn to_json_value(&self) -> Value {
+        let (rows, columns) = self.shape;
+        json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "verdict": match self.verdict() {
+                Verdict::Passed => "passed",
+                Verdict::Warning => "warning",
+                Verdict::Failed => "failed",
+            },
+            "shape": { "rows": rows, "columns": columns },
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+            "summary": {
+                "rules": self.rule_count,
+                "load_findings": self.load_findings().len(),
+                "passed": self.passed_count(),
+                "failed": self.failed_count(),
+                "warnings": self.warning_count(),
+                "info": self.info_count(),
+            },
+            "results": self.results.iter().map(result_to_json).collect::<Vec<_>>(),
+        })
+    }
+
+    /// The report as a pretty-printed JSON document.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.to_json_value())
+            .expect("report JSON values always serialize")
+    }
+}
+
+fn result_to_json(result: &ValidationResult) -> Value {
+    json!({
+        "column": result.column,
+        "constraint": result.constraint,
+        "status": result.status().to_lowercase(),
+        "passed": result.passed,
+        "severity": result.severity.to_string(),
+        "failed_count": result.failed_count,
+        "evaluated_count": result.evaluated_count,
+        "failed_ratio": result.failed_ratio,
+        "tolerance": result.tolerance.map(|tolerance| match tolerance {
+            Tolerance::Mostly(fraction) => json!({ "mostly": fraction }),
+            Tolerance::MaxFailures(count) => json!({ "max_failures": count }),
+        }),
+        "error": result.error,
+        "failed_rows": result.failed_rows,
+        "sample_values": result.sample_values,
+        "observed": result.observed,
+    })
+}
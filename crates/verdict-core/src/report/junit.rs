@@ -0,0 +1,83 @@
+//! JUnit XML report output.
+//!
+//! Every result, rules and load findings alike, becomes one `<testcase>` with
+//! the column as `classname` and the constraint as `name`, so `tests` counts
+//! both. Failed error-severity results are reported as `<failure>`; failed
+//! warning and info rules stay green and carry their result line in
+//! `<system-out>`, so they show up without breaking the build.
+
+use std::fmt::Write;
+
+use super::Report;
+use crate::rules::Severity;
+
+impl Report {
+    pub fn to_junit(&self) -> String {
+        let seconds = self.elapsed.as_secs_f64();
+        let tests = self.len();
+        let failures = self.failed_count();
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuites name=\"verdict\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.6}\">",
+            tests, failures, seconds
+        );
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"verdict\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.6}\">",
+            tests, failures, seconds
+        );
+        for result in &self.results {
+            let _ = write!(
+                out,
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape(&result.column),
+                escape(&result.constraint)
+            );
+            if result.passed {
+                out.push_str("/>\n");
+                continue;
+            }
+            out.push_str(">\n");
+            if result.severity == Severity::Error {
+                let _ = writeln!(
+                    out,
+                    "      <failure message=\"{}\" type=\"{}\">{}</failure>",
+                    escape(result.error.as_deref().unwrap_or("unknown error")),
+                    result.severity,
+                    escape(&result.to_string())
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    "      <system-out>{}</system-out>",
+                    escape(&result.to_string())
+                );
+            }
+            out.push_str("    </testcase>\n");
+        }
+        out.push_str("  </testsuite>\n</testsuites>\n");
+        out
+    }
+}
+
+/// Escapes `text` for XML attributes and text. Control characters other than
+/// tab, LF and CR aren't allowed in XML 1.0, even escaped, so they become
+/// U+FFFD.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1f}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
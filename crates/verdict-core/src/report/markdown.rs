@@ -0,0 +1,75 @@
+//! Markdown report output, sized for pull request comments.
+
+use std::fmt::Write;
+
+use super::Report;
+
+impl Report {
+    /// A verdict heading, a one-line summary and a table with one row per result.
+    pub fn to_markdown(&self) -> String {
+        let (rows, cols) = self.shape;
+        let mut out = String::new();
+        let _ = writeln!(out, "### Verdict: {}", self.verdict());
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "{} rows × {} columns, {}: {} passed, {} failed, {} warnings, {} info",
+            rows,
+            cols,
+            self.checks_label(),
+            self.passed_count(),
+            self.failed_count(),
+            self.warning_count(),
+            self.info_count()
+        );
+        if self.is_empty() {
+            return out;
+        }
+
+        let _ = writeln!(out);
+        out.push_str("| Status | Column | Constraint | Failed | Details |\n");
+        out.push_str("| --- | --- | --- | ---: | --- |\n");
+        for result in &self.results {
+            let failed = if result.evaluated_count > 0 {
+                format!("{}/{}", result.failed_count, result.evaluated_count)
+            } else {
+                result.failed_count.to_string()
+            };
+            let mut details = result.error.clone().unwrap_or_default();
+            if let Some(observed) = result.observed {
+                if !details.is_empty() {
+                    details.push_str("; ");
+                }
+                let _ = write!(details, "observed: {}", observed);
+            }
+            if !result.sample_values.is_empty() {
+                if !details.is_empty() {
+                    details.push_str("; ");
+                }
+                details.push_str("sample: ");
+                details.push_str(&result.sample_values.join(", "));
+            }
+            if let Some(tolerance) = &result.tolerance {
+                if !details.is_empty() {
+                    details.push_str("; ");
+                }
+                details.push_str(&tolerance.to_string());
+            }
+            let _ = writeln!(
+                out,
+                "| {} | `{}` | `{}` | {} | {} |",
+                result.status(),
+                cell(&result.column),
+                cell(&result.constraint),
+                failed,
+                cell(&details)
+            );
+        }
+        out
+    }
+}
+
+/// Keeps a value inside its table cell.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
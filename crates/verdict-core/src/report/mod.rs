@@ -0,0 +1,255 @@
+#[cfg(feature = "formats")]
+mod json;
+#[cfg(feature = "formats")]
+mod junit;
+#[cfg(feature = "formats")]
+mod markdown;
+
+#[cfg(feature = "formats")]
+pub use json::JSON_SCHEMA_VERSION;
+
+use std::time::Duration;
+
+use crate::rules::{Severity, ValidationResult, Verdict};
+
+/// Outcome of a validation run: the per-rule results plus run metadata.
+pub struct Report {
+    /// One result per rule, in rule order, then one per load finding: a
+    /// column with cells coerced to null, or rows with cells past the header.
+    pub results: Vec<ValidationResult>,
+    /// Number of leading `results` that come from rules.
+    pub rule_count: usize,
+    /// `(rows, columns)` of the validated dataset.
+    pub shape: (usize, usize),
+    pub elapsed: Duration,
+}
+
+/// Per-column rollup of rule outcomes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSummary {
+    pub column: String,
+    pub rules: usize,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl Report {
+    /// A report of rule results only; add load findings with `with_load_findings`.
+    pub fn new(results: Vec<ValidationResult>, shape: (usize, usize), elapsed: Duration) -> Self {
+        Report {
+            rule_count: results.len(),
+            results,
+            shape,
+            elapsed,
+        }
+    }
+
+    /// Appends results about the data as loaded, after the rule results.
+    pub fn with_load_findings(mut self, findings: Vec<ValidationResult>) -> Report {
+        self.results.extend(findings);
+        self
+    }
+
+    /// Number of results, load findings included; see `rule_count` for rules only.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Results about the data as loaded rather than about a rule.
+    pub fn load_findings(&self) -> &[ValidationResult] {
+        &self.results[self.rule_count..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationResult> {
+        self.results.iter()
+    }
+
+    /// True when every rule passed, regardless of severity.
+    pub fn all_passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed)
+    }
+
+    /// Every failed result, regardless of severity.
+    pub fn failed(&self) -> impl Iterator<Item = &ValidationResult> {
+        self.results.iter().filter(|r| !r.passed)
+    }
+
+    /// Failed results of exactly the given severity.
+    pub fn failed_with(&self, severity: Severity) -> impl Iterator<Item = &ValidationResult> {
+        self.failed().filter(move |r| r.severity == severity)
+    }
+
+    pub fn passed_count(&self) -> usize {
+        self.results.iter().filter(|r| r.passed).count()
+    }
+
+    /// Failed error-severity rules.
+    pub fn failed_count(&self) -> usize {
+        self.failed_with(Severity::Error).count()
+    }
+
+    /// Failed warning-severity rules.
+    pub fn warning_count(&self) -> usize {
+        self.failed_with(Severity::Warning).count()
+    }
+
+    /// Failed info-severity rules.
+    pub fn info_count(&self) -> usize {
+        self.failed_with(Severity::Info).count()
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        Verdict::from_results(&self.results)
+    }
+
+    /// Rule outcomes grouped by column, in order of first appearance.
+    pub fn column_summaries(&self) -> Vec<ColumnSummary> {
+        let mut summaries: Vec<ColumnSummary> = Vec::new();
+        for result in &self.results {
+            let idx = match summaries.iter().position(|s| s.column == result.column) {
+                Some(idx) => idx,
+                None => {
+                    summaries.push(ColumnSummary {
+                        column: result.column.clone(),
+                        rules: 0,
+                        passed: 0,
+                        failed: 0,
+                    });
+                    summaries.len() - 1
+                }
+            };
+            let summary = &mut summaries[idx];
+            summary.rules += 1;
+            if result.passed {
+                summary.passed += 1;
+            } else {
+                summary.failed += 1;
+            }
+        }
+        summaries
+    }
+}
+
+impl Report {
+    /// e.g. "3 rules" or "3 rules, 1 load finding".
+    fn checks_label(&self) -> String {
+        let plural = |count: usize, noun: &str| {
+            format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+        };
+        let mut label = plural(self.rule_count, "rule");
+        let findings = self.load_findings().len();
+        if findings > 0 {
+            label.push_str(&format!(", {}", plural(findings, "load finding")));
+        }
+        label
+    }
+}
+
+impl std::ops::Index<usize> for Report {
+    type Output = ValidationResult;
+
+    fn index(&self, idx: usize) -> &ValidationResult {
+        &self.results[idx]
+    }
+}
+
+impl<'a> IntoIterator for &'a Report {
+    type Item = &'a ValidationResult;
+    type IntoIter = std::slice::Iter<'a, ValidationResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
+
+impl IntoIterator for Report {
+    type Item = ValidationResult;
+    type IntoIter = std::vec::IntoIter<ValidationResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (rows, cols) = self.shape;
+        writeln!(
+            f,
+            "Validation report: {} rows × {} columns, {} in {:.2?}",
+            rows,
+            cols,
+            self.checks_label(),
+            self.elapsed
+        )?;
+        writeln!(
+            f,
+            "Verdict: {} ({} passed, {} failed, {} warnings, {} info)",
+            self.verdict(),
+            self.passed_count(),
+            self.failed_count(),
+            self.warning_count(),
+            self.info_count()
+        )?;
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let rows: Vec<[String; 4]> = self
+            .results
+            .iter()
+            .map(|r| {
+                let failed = if r.evaluated_count > 0 {
+                    format!("{}/{}", r.failed_count, r.evaluated_count)
+                } else {
+                    r.failed_count.to_string()
+                };
+                [
+                    r.status().to_string(),
+                    r.column.clone(),
+                    r.constraint.clone(),
+                    failed,
+                ]
+            })
+            .collect();
+        let header = ["STATUS", "COLUMN", "CONSTRAINT", "FAILED"];
+        let mut widths = header.map(|h| h.chars().count());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        writeln!(f)?;
+        write_row(f, &header.map(String::from), &widths)?;
+        for row in &rows {
+            write_row(f, row, &widths)?;
+        }
+
+        let failures: Vec<&ValidationResult> = self.failed().collect();
+        if !failures.is_empty() {
+            writeln!(f)?;
+            for result in failures {
+                writeln!(f, "{}", result)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_row(
+    f: &mut std::fmt::Formatter<'_>,
+    cells: &[String; 4],
+    widths: &[usize; 4],
+) -> std::fmt::Result {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect();
+    writeln!(f, "{}", line.join("  ").trim_end())
+}
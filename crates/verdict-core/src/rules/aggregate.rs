@@ -0,0 +1,200 @@
+//! Aggregate constraints: one statistic per column instead of one outcome
+//! per row.
+//!
+//! Statistics are computed from an `Aggregate`, which can be built batch by
+//! batch and merged, so streaming validation reports the same observed values
+//! as `validate`.
+
+use std::collections::HashSet;
+
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+
+use super::{Constraint, NullPolicy, Rule, ValidationResult, rule_result};
+use crate::dataset::{Column, ColumnStats, ops::decimal_from_f64};
+
+/// The parts of a column an aggregate constraint needs, over the rows seen
+/// so far.
+#[derive(Clone, Default)]
+pub(super) struct Aggregate {
+    stats: ColumnStats,
+    /// Non-null values, for `MedianBetween` only.
+    values: Vec<f64>,
+    /// Keys of the non-null values, for `DistinctCountBetween` only.
+    distinct: HashSet<String>,
+    /// Exact sum of a Decimal column, for `SumEqual` only.
+    decimal_sum: DecimalSum,
+}
+
+#[derive(Clone, Copy, Default)]
+enum DecimalSum {
+    /// No Decimal values seen; the sum comes from `ColumnStats`.
+    #[default]
+    Empty,
+    Exact(Decimal),
+    /// Too large for `Decimal`; the sum falls back to `ColumnStats`.
+    Overflowed,
+}
+
+impl DecimalSum {
+    fn add(self, value: Decimal) -> DecimalSum {
+        match self {
+            DecimalSum::Empty => DecimalSum::Exact(value),
+            DecimalSum::Exact(sum) => sum
+                .checked_add(value)
+                .map_or(DecimalSum::Overflowed, DecimalSum::Exact),
+            DecimalSum::Overflowed => DecimalSum::Overflowed,
+        }
+    }
+
+    fn merge(self, other: DecimalSum) -> DecimalSum {
+        match other {
+            DecimalSum::Empty => self,
+            DecimalSum::Exact(sum) => self.add(sum),
+            DecimalSum::Overflowed => DecimalSum::Overflowed,
+        }
+    }
+}
+
+impl Aggregate {
+    pub(super) fn of(constraint: &Constraint, column: &Column) -> Self {
+        let values = match constraint {
+            Constraint::MedianBetween { .. } => numeric_values(column),
+            _ => Vec::new(),
+        };
+        let distinct = match constraint {
+            Constraint::DistinctCountBetween { .. } => (0..column.len())
+                .filter_map(|row| column.key_value(row))
+                .collect(),
+            _ => HashSet::new(),
+        };
+        let decimal_sum = match (constraint, column) {
+            (Constraint::SumEqual(_), Column::Decimal(col)) => col
+                .0
+                .iter()
+                .flatten()
+                .fold(DecimalSum::Empty, |sum, v| sum.add(*v)),
+            _ => DecimalSum::Empty,
+        };
+        Aggregate {
+            stats: ColumnStats::from_column(column),
+            values,
+            distinct,
+            decimal_sum,
+        }
+    }
+
+    /// Folds in the aggregate of more rows of the same column.
+    pub(super) fn merge(&mut self, other: Aggregate) {
+        self.stats.merge(&other.stats);
+        self.values.extend(other.values);
+        self.distinct.extend(other.distinct);
+        self.decimal_sum = self.decimal_sum.merge(other.decimal_sum);
+    }
+
+    /// The statistic `constraint` checks; `None` when it is undefined, e.g.
+    /// the mean of a column without non-null values.
+    pub(super) fn observed(&self, constraint: &Constraint) -> Option<f64> {
+        let stats = &self.stats;
+        match constraint {
+            Constraint::MeanBetween { .. } => stats.mean(),
+            Constraint::SumEqual(_) => match self.decimal_sum {
+                DecimalSum::Exact(sum) => sum.to_f64(),
+                _ => stats.sum(),
+            },
+            Constraint::StdBelow(_) => stats.std(),
+            Constraint::MedianBetween { .. } => median(&self.values),
+            Constraint::MinBetween { .. } => stats.min(),
+            Constraint::MaxBetween { .. } => stats.max(),
+            Constraint::NullRatioAtMost(_) => {
+                (stats.count > 0).then(|| stats.null_count as f64 / stats.count as f64)
+            }
+            Constraint::DistinctCountBetween { .. } => Some(self.distinct.len() as f64),
+            Constraint::RowCountBetween { .. } => Some(stats.count as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `observed` satisfies the aggregate `constraint`.
+fn accepts(constraint: &Constraint, observed: f64) -> bool {
+    match constraint {
+        Constraint::MeanBetween { min, max }
+        | Constraint::MedianBetween { min, max }
+        | Constraint::MinBetween { min, max }
+        | Constraint::MaxBetween { min, max } => (*min..=*max).contains(&observed),
+        Constraint::SumEqual(expected) => {
+            (observed - expected).abs() <= 1e-9 * expected.abs().max(1.0)
+        }
+        Constraint::StdBelow(bound) => observed < *bound,
+        Constraint::NullRatioAtMost(bound) => observed <= *bound,
+        Constraint::DistinctCountBetween { min, max }
+        | Constraint::RowCountBetween { min, max } => {
+            (*min as f64..=*max as f64).contains(&observed)
+        }
+        _ => false,
+    }
+}
+
+/// The result of an aggregate rule: a single check of the observed
+/// statistic. An undefined statistic is handled by the rule's null policy.
+pub(super) fn aggregate_result(rule: &Rule, aggregate: &Aggregate) -> ValidationResult {
+    let constraint = &rule.constraint;
+    let Some(observed) = aggregate.observed(constraint) else {
+        return match rule.null_policy {
+            NullPolicy::Fail => ValidationResult::failed(rule, 0, &undefined_message(constraint)),
+            NullPolicy::Pass | NullPolicy::Exclude => ValidationResult::passed(rule),
+        };
+    };
+    let failed = match (constraint, aggregate.decimal_sum) {
+        // Decimal sums are compared exactly, with the expected sum as written
+        (Constraint::SumEqual(expected), DecimalSum::Exact(sum)) => {
+            decimal_from_f64(*expected) != Some(sum)
+        }
+        _ => !accepts(constraint, observed),
+    };
+    ValidationResult {
+        observed: Some(observed),
+        ..rule_result(rule, usize::from(failed), 1, Vec::new(), Vec::new())
+    }
+}
+
+fn undefined_message(constraint: &Constraint) -> String {
+    let statistic = match constraint {
+        Constraint::NullRatioAtMost(_) => {
+            return "null ratio is undefined without rows".to_string();
+        }
+        Constraint::StdBelow(_) => {
+            return "std is undefined with fewer than two non-null values".to_string();
+        }
+        Constraint::MeanBetween { .. } => "mean",
+        Constraint::SumEqual(_) => "sum",
+        Constraint::MedianBetween { .. } => "median",
+        Constraint::MinBetween { .. } => "min",
+        Constraint::MaxBetween { .. } => "max",
+        _ => "statistic",
+    };
+    format!("{} is undefined without non-null values", statistic)
+}
+
+fn numeric_values(column: &Column) -> Vec<f64> {
+    match column {
+        Column::Int(col) => col.valid_values().map(|v| v as f64).collect(),
+        Column::Float(col) => col.valid_values().collect(),
+        Column::Decimal(col) => col.0.iter().flatten().filter_map(|v| v.to_f64()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut values = values.to_vec();
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        Some(values[mid])
+    }
+}
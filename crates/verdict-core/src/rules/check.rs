@@ -0,0 +1,240 @@
+//! Pre-flight checks of rules against column types.
+
+use super::{Constraint, Rule, Tolerance};
+use crate::{
+    dataset::{DataType, InSetValues, Schema},
+    errors::ValidationError,
+};
+
+/// Checks every rule, and its condition, against the column types in
+/// `schema` without reading any data. Returns one error per rule that can't
+/// run: a missing column, or a constraint that doesn't apply to the column's
+/// type (e.g. `GreaterThan` on a Str column).
+///
+/// `validate` reports such rules as failed with the same error; run this
+/// first, e.g. on `Dataset::schema()`, to abort before validating instead.
+pub fn check_rules(schema: &Schema, rules: &[Rule]) -> Vec<ValidationError> {
+    let dtype_of = |name: &str| {
+        schema
+            .fields
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| f.dtype.clone())
+    };
+    rules
+        .iter()
+        .enumerate()
+        .filter_map(|(index, rule)| check_rule(index, rule, dtype_of).err())
+        .collect()
+}
+
+pub(super) fn check_rule(
+    index: usize,
+    rule: &Rule,
+    dtype_of: impl Fn(&str) -> Option<DataType>,
+) -> Result<(), ValidationError> {
+    let typed = |name: &str| {
+        dtype_of(name)
+            .map(|dtype| (name.to_string(), dtype))
+            .ok_or_else(|| ValidationError::ColumnNotFound {
+                name: name.to_string(),
+            })
+    };
+
+    let columns = rule
+        .columns()
+        .into_iter()
+        .map(typed)
+        .collect::<Result<Vec<_>, _>>()?;
+    check_constraint(index, rule, &columns, &rule.constraint)?;
+    // `Rule::mostly` and deserialization check this, but the field is public
+    if let Some(Tolerance::Mostly(fraction)) = rule.tolerance
+        && !(0.0..=1.0).contains(&fraction)
+    {
+        return Err(ValidationError::InvalidTolerance {
+            index,
+            column: rule.column.clone(),
+            fraction,
+        });
+    }
+    // an aggregate is a single check, which a tolerance would simply overrule
+    if rule.constraint.is_aggregate() && rule.tolerance.is_some() {
+        return Err(misplaced_aggregate(
+            index,
+            rule,
+            &rule.constraint,
+            "with a tolerance",
+        ));
+    }
+
+    if let Some(condition) = &rule.condition {
+        if condition.constraint.is_aggregate() {
+            return Err(misplaced_aggregate(
+                index,
+                rule,
+                &condition.constraint,
+                "as a condition",
+            ));
+        }
+        if !condition.constraint.is_composable() {
+            return Err(not_composable(
+                index,
+                rule,
+                &condition.constraint,
+                "as a condition",
+            ));
+        }
+        let column = typed(&condition.column)?;
+        check_constraint(index, rule, &[column], &condition.constraint)?;
+    }
+    Ok(())
+}
+
+/// `columns` are the constrained columns with their types, in
+/// `Rule::columns` order.
+fn check_constraint(
+    index: usize,
+    rule: &Rule,
+    columns: &[(String, DataType)],
+    constraint: &Constraint,
+) -> Result<(), ValidationError> {
+    let dtype = &columns[0].1;
+    let is_numeric =
+        |dtype: &DataType| matches!(dtype, DataType::Int | DataType::Float | DataType::Decimal);
+    let numeric = is_numeric(dtype);
+    let is_text = |dtype: &DataType| matches!(dtype, DataType::Str | DataType::Categorical);
+    let text = is_text(dtype);
+    let (compatible, expected) = match constraint {
+        Constraint::NotNull
+        | Constraint::Unique
+        | Constraint::UniqueWith(_)
+        | Constraint::ExactlyOneNotNull(_)
+        | Constraint::Increasing { .. }
+        | Constraint::Decreasing { .. } => (true, ""),
+        Constraint::DateBetween { .. }
+        | Constraint::NotInFuture
+        | Constraint::Freshness { .. }
+        | Constraint::IsWeekday
+        | Constraint::IsWeekend => (
+            matches!(dtype, DataType::Date | DataType::DateTime),
+            "a Date or DateTime column",
+        ),
+        Constraint::GreaterThan(_)
+        | Constraint::GreaterThanOrEqual(_)
+        | Constraint::LessThan(_)
+        | Constraint::LessThanOrEqual(_)
+        | Constraint::Equal(_)
+        | Constraint::Between { .. }
+        | Constraint::MeanBetween { .. }
+        | Constraint::SumEqual(_)
+        | Constraint::StdBelow(_)
+        | Constraint::MedianBetween { .. }
+        | Constraint::MinBetween { .. }
+        | Constraint::MaxBetween { .. } => (numeric, "an Int, Float or Decimal column"),
+        Constraint::NullRatioAtMost(_)
+        | Constraint::DistinctCountBetween { .. }
+        | Constraint::RowCountBetween { .. } => (true, ""),
+        Constraint::MaxScale(_) | Constraint::Precision { .. } => (
+            matches!(dtype, DataType::Int | DataType::Decimal),
+            "an Int or Decimal column",
+        ),
+        Constraint::MatchesRegex(_)
+        | Constraint::Contains(_)
+        | Constraint::StartsWith(_)
+        | Constraint::EndsWith(_)
+        | Constraint::LengthBetween { .. } => (text, "a Str or Categorical column"),
+        Constraint::InSet(InSetValues::IntSet(_)) => (*dtype == DataType::Int, "an Int column"),
+        Constraint::InSet(InSetValues::FloatSet(_)) => (
+            matches!(dtype, DataType::Float | DataType::Decimal),
+            "a Float or Decimal column",
+        ),
+        Constraint::InSet(InSetValues::StrSet(_)) => (text, "a Str or Categorical column"),
+        Constraint::GreaterThanColumn(_)
+        | Constraint::GreaterThanOrEqualColumn(_)
+        | Constraint::LessThanColumn(_)
+        | Constraint::LessThanOrEqualColumn(_)
+        | Constraint::EqualColumn(_) => {
+            let other = &columns[1].1;
+            (
+                (numeric && is_numeric(other)) || (text && is_text(other)) || dtype == other,
+                "columns of comparable types (both numeric, both text or the same type)",
+            )
+        }
+        Constraint::All(constraints) | Constraint::Any(constraints) => {
+            for constraint in constraints {
+                check_nested(index, rule, columns, constraint)?;
+            }
+            return Ok(());
+        }
+        Constraint::Not(constraint) => return check_nested(index, rule, columns, constraint),
+    };
+    if compatible {
+        return Ok(());
+    }
+
+    let found: Vec<String> = columns
+        .iter()
+        .map(|(name, dtype)| format!("'{}' ({:?})", name, dtype))
+        .collect();
+    Err(ValidationError::IncompatibleType {
+        index,
+        column: rule.column.clone(),
+        constraint: constraint.to_string(),
+        expected: expected.to_string(),
+        found: found.join(" and "),
+    })
+}
+
+/// Nested constraints see only the rule's own column, one row at a time.
+fn check_nested(
+    index: usize,
+    rule: &Rule,
+    columns: &[(String, DataType)],
+    constraint: &Constraint,
+) -> Result<(), ValidationError> {
+    if constraint.is_aggregate() {
+        return Err(misplaced_aggregate(
+            index,
+            rule,
+            constraint,
+            "inside All, Any or Not",
+        ));
+    }
+    if !constraint.is_composable() {
+        return Err(not_composable(
+            index,
+            rule,
+            constraint,
+            "inside All, Any or Not",
+        ));
+    }
+    check_constraint(index, rule, &columns[..1], constraint)
+}
+
+fn not_composable(
+    index: usize,
+    rule: &Rule,
+    constraint: &Constraint,
+    place: &str,
+) -> ValidationError {
+    ValidationError::NotComposable {
+        index,
+        column: rule.column.clone(),
+        constraint: constraint.to_string(),
+        place: place.to_string(),
+    }
+}
+
+fn misplaced_aggregate(
+    index: usize,
+    rule: &Rule,
+    constraint: &Constraint,
+    place: &str,
+) -> ValidationError {
+    ValidationError::MisplacedAggregate {
+        index,
+        column: rule.column.clone(),
+        constraint: constraint.to_string(),
+        place: place.to_string(),
+    }
+}
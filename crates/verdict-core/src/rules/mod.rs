@@ -1,27 +1,269 @@
+mod aggregate;
+mod check;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod streaming;
+
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeDelta, Utc};
+use regex::Regex;
+
+use aggregate::{Aggregate, aggregate_result};
+pub use check::check_rules;
+pub use streaming::StreamingValidator;
+
 use crate::{
-    dataset::{Column, Dataset, InSetValues},
+    dataset::{Coercion, Column, DataType, Dataset, InSetValues, Mask},
     errors::ValidationError,
+    report::Report,
 };
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
 pub struct Rule {
     pub column: String,
     pub constraint: Constraint,
+    /// Restricts the rule to rows where the condition holds.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "when", default, skip_serializing_if = "Option::is_none")
+    )]
+    pub condition: Option<Condition>,
+    /// Failing rows the rule accepts before it fails; `None` accepts none.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub tolerance: Option<Tolerance>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: Severity,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub null_policy: NullPolicy,
 }
 
-#[derive(Debug, Clone)]
+/// What a rule does with rows its check can't decide because of a null: a
+/// null value, a null on either side of a cross-column comparison, or a null
+/// in a `Unique`/`UniqueWith` key. `NotNull` and `ExactlyOneNotNull` decide
+/// every row, so the policy doesn't affect them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum NullPolicy {
+    /// Null rows fail; the default, so `GreaterThan(0.0)` on a nullable
+    /// column fails for every null.
+    #[default]
+    Fail,
+    /// Null rows pass.
+    Pass,
+    /// Null rows are skipped: they neither fail nor count towards
+    /// `evaluated_count`, so `mostly` is a fraction of the non-null rows.
+    Exclude,
+}
+
+impl NullPolicy {
+    /// Parses `"fail"`, `"pass"` or `"exclude"` (case-insensitive).
+    pub fn parse(s: &str) -> Option<NullPolicy> {
+        match s.to_lowercase().as_str() {
+            "fail" => Some(NullPolicy::Fail),
+            "pass" => Some(NullPolicy::Pass),
+            "exclude" => Some(NullPolicy::Exclude),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for NullPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NullPolicy::Fail => write!(f, "fail"),
+            NullPolicy::Pass => write!(f, "pass"),
+            NullPolicy::Exclude => write!(f, "exclude"),
+        }
+    }
+}
+
+/// How much a failing rule matters. Only `Error` failures fail the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    /// Parses `"error"`, `"warning"` or `"info"` (case-insensitive).
+    pub fn parse(s: &str) -> Option<Severity> {
+        match s.to_lowercase().as_str() {
+            "error" => Some(Severity::Error),
+            "warning" | "warn" => Some(Severity::Warning),
+            "info" => Some(Severity::Info),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// Overall outcome of a validation run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// No rule failed, or only info-level rules did.
+    Passed,
+    /// Warning-level rules failed; nothing blocking.
+    Warning,
+    /// At least one error-level rule failed.
+    Failed,
+}
+
+impl Verdict {
+    pub fn from_results<'a>(results: impl IntoIterator<Item = &'a ValidationResult>) -> Verdict {
+        let worst = results
+            .into_iter()
+            .filter(|r| !r.passed)
+            .map(|r| r.severity)
+            .max();
+        match worst {
+            Some(Severity::Error) => Verdict::Failed,
+            Some(Severity::Warning) => Verdict::Warning,
+            Some(Severity::Info) | None => Verdict::Passed,
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Passed => write!(f, "PASSED"),
+            Verdict::Warning => write!(f, "PASSED WITH WARNINGS"),
+            Verdict::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// How many failing rows a rule tolerates and still passes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Tolerance {
+    /// At least this fraction (0.0–1.0) of evaluated rows must pass, e.g.
+    /// `Mostly(0.99)` allows up to 1% failures.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_fraction"))]
+    Mostly(f64),
+    /// At most this many rows may fail.
+    MaxFailures(usize),
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_fraction<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<f64, D::Error> {
+    let fraction = <f64 as serde::Deserialize>::deserialize(deserializer)?;
+    if (0.0..=1.0).contains(&fraction) {
+        Ok(fraction)
+    } else {
+        Err(serde::de::Error::custom(format!(
+            "mostly must be between 0.0 and 1.0, got {}",
+            fraction
+        )))
+    }
+}
+
+impl Tolerance {
+    pub fn allows(&self, failed_count: usize, evaluated_count: usize) -> bool {
+        match self {
+            Tolerance::Mostly(fraction) => {
+                evaluated_count == 0
+                    || (evaluated_count - failed_count) as f64 / evaluated_count as f64 >= *fraction
+            }
+            Tolerance::MaxFailures(max) => failed_count <= *max,
+        }
+    }
+}
+
+impl std::fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tolerance::Mostly(fraction) => {
+                write!(f, "allowed {:.2}% failed", (1.0 - fraction) * 100.0)
+            }
+            Tolerance::MaxFailures(max) => write!(f, "allowed {} failed", max),
+        }
+    }
+}
+
+/// Row filter for a conditional rule: rows where `constraint` passes on
+/// `column` are checked, all other rows are skipped. The constraint must read
+/// only `column`, one row at a time, as inside `All`, `Any` or `Not`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct Condition {
+    pub column: String,
+    pub constraint: Constraint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Constraint {
     // Null checks
     NotNull,
     Unique,
 
-    // Numeric comparisons
+    // Numeric comparisons; on Decimal columns the bound is taken as written
+    // (`0.1` is exactly 0.1) and compared exactly
     GreaterThan(f64),
     GreaterThanOrEqual(f64),
     LessThan(f64),
     LessThanOrEqual(f64),
     Equal(f64),
-    Between { min: f64, max: f64 },
+    Between {
+        min: f64,
+        max: f64,
+    },
+    /// At most this many decimal places, ignoring trailing zeros.
+    MaxScale(u32),
+    /// Fits SQL `NUMERIC(precision, scale)`.
+    Precision {
+        precision: u32,
+        scale: u32,
+    },
 
     // String checks
     InSet(InSetValues),
@@ -29,15 +271,302 @@ pub enum Constraint {
     Contains(String),
     StartsWith(String),
     EndsWith(String),
-    LengthBetween { min: usize, max: usize },
+    LengthBetween {
+        min: usize,
+        max: usize,
+    },
+
+    // Cross-column checks: the rule's column against the named columns, row by row
+    GreaterThanColumn(String),
+    GreaterThanOrEqualColumn(String),
+    LessThanColumn(String),
+    LessThanOrEqualColumn(String),
+    EqualColumn(String),
+    /// The rule's column together with these columns forms a unique key.
+    UniqueWith(Vec<String>),
+    /// Exactly one of the rule's column and these columns is non-null.
+    ExactlyOneNotNull(Vec<String>),
+
+    // Date and DateTime checks; DateTime values use the date in their own offset
+    /// Calendar date within `min..=max`.
+    DateBetween {
+        min: NaiveDate,
+        max: NaiveDate,
+    },
+    /// Not later than the time of validation (today, in UTC, for Date columns).
+    NotInFuture,
+    /// At most `max_age_secs` older than `reference`, which defaults to the
+    /// time of validation.
+    Freshness {
+        max_age_secs: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        reference: Option<DateTime<FixedOffset>>,
+    },
+    IsWeekday,
+    IsWeekend,
+
+    // Ordering checks: each non-null value against the previous non-null value
+    Increasing {
+        #[cfg_attr(feature = "serde", serde(default))]
+        strict: bool,
+    },
+    Decreasing {
+        #[cfg_attr(feature = "serde", serde(default))]
+        strict: bool,
+    },
+
+    // Aggregate checks: one statistic of the rule's column (of the rows
+    // matching the condition, for conditional rules), reported in
+    // `ValidationResult::observed`. Statistics skip nulls.
+    MeanBetween {
+        min: f64,
+        max: f64,
+    },
+    /// Equal up to a relative difference of 1e-9, to absorb rounding; exact
+    /// for Decimal columns, with the expected sum taken as written.
+    SumEqual(f64),
+    /// Sample standard deviation strictly below the bound.
+    StdBelow(f64),
+    MedianBetween {
+        min: f64,
+        max: f64,
+    },
+    MinBetween {
+        min: f64,
+        max: f64,
+    },
+    MaxBetween {
+        min: f64,
+        max: f64,
+    },
+    /// Share of null rows, 0.0–1.0.
+    NullRatioAtMost(f64),
+    /// Number of distinct non-null values.
+    DistinctCountBetween {
+        min: usize,
+        max: usize,
+    },
+    /// Number of rows the rule evaluates (matching rows when conditional); the
+    /// rule's column can be any column.
+    RowCountBetween {
+        min: usize,
+        max: usize,
+    },
+
+    // Combinators over checks of the rule's own column, composed with SQL's
+    // three-valued logic: a null row is null under `Not` and only decided by
+    // `All`/`Any` when another branch is false/true. Cross-column and ordering
+    // checks can't be nested.
+    /// Every nested constraint passes.
+    All(Vec<Constraint>),
+    /// At least one nested constraint passes, e.g. null or between 0 and 100:
+    /// `Any(vec![Not(Box::new(NotNull)), Between { .. }])`.
+    Any(Vec<Constraint>),
+    /// The nested constraint fails.
+    Not(Box<Constraint>),
 }
 
+/// Short human-readable form, e.g. `between 0 and 100` or
+/// `starts with 'SKU-' and length between 8 and 12`.
+impl std::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quoted = |names: &[String]| {
+            names
+                .iter()
+                .map(|n| format!("'{}'", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let strict = |strict: bool| if strict { "strictly " } else { "" };
+        match self {
+            Constraint::NotNull => write!(f, "not null"),
+            Constraint::Unique => write!(f, "unique"),
+            Constraint::GreaterThan(v) => write!(f, "> {}", v),
+            Constraint::GreaterThanOrEqual(v) => write!(f, ">= {}", v),
+            Constraint::LessThan(v) => write!(f, "< {}", v),
+            Constraint::LessThanOrEqual(v) => write!(f, "<= {}", v),
+            Constraint::Equal(v) => write!(f, "= {}", v),
+            Constraint::Between { min, max } => write!(f, "between {} and {}", min, max),
+            Constraint::MaxScale(max) => write!(f, "at most {} decimal places", max),
+            Constraint::Precision { precision, scale } => {
+                write!(f, "fits NUMERIC({}, {})", precision, scale)
+            }
+            Constraint::InSet(InSetValues::IntSet(values)) => {
+                let values: Vec<String> = values.iter().map(i64::to_string).collect();
+                write!(f, "in {{{}}}", values.join(", "))
+            }
+            Constraint::InSet(InSetValues::FloatSet(values)) => {
+                let values: Vec<String> = values.iter().map(f64::to_string).collect();
+                write!(f, "in {{{}}}", values.join(", "))
+            }
+            Constraint::InSet(InSetValues::StrSet(values)) => {
+                write!(f, "in {{{}}}", quoted(values))
+            }
+            Constraint::MatchesRegex(p) => write!(f, "matches regex '{}'", p),
+            Constraint::Contains(p) => write!(f, "contains '{}'", p),
+            Constraint::StartsWith(p) => write!(f, "starts with '{}'", p),
+            Constraint::EndsWith(p) => write!(f, "ends with '{}'", p),
+            Constraint::LengthBetween { min, max } => {
+                write!(f, "length between {} and {}", min, max)
+            }
+            Constraint::GreaterThanColumn(c) => write!(f, "> column '{}'", c),
+            Constraint::GreaterThanOrEqualColumn(c) => write!(f, ">= column '{}'", c),
+            Constraint::LessThanColumn(c) => write!(f, "< column '{}'", c),
+            Constraint::LessThanOrEqualColumn(c) => write!(f, "<= column '{}'", c),
+            Constraint::EqualColumn(c) => write!(f, "= column '{}'", c),
+            Constraint::UniqueWith(others) => write!(f, "unique with {}", quoted(others)),
+            Constraint::ExactlyOneNotNull(others) => {
+                write!(f, "exactly one not null with {}", quoted(others))
+            }
+            Constraint::DateBetween { min, max } => write!(f, "date between {} and {}", min, max),
+            Constraint::NotInFuture => write!(f, "not in future"),
+            Constraint::Freshness {
+                max_age_secs,
+                reference: Some(reference),
+            } => write!(
+                f,
+                "at most {}s older than {}",
+                max_age_secs,
+                reference.to_rfc3339()
+            ),
+            Constraint::Freshness {
+                max_age_secs,
+                reference: None,
+            } => write!(f, "at most {}s old", max_age_secs),
+            Constraint::IsWeekday => write!(f, "weekday"),
+            Constraint::IsWeekend => write!(f, "weekend"),
+            Constraint::Increasing { strict: s } => write!(f, "{}increasing", strict(*s)),
+            Constraint::Decreasing { strict: s } => write!(f, "{}decreasing", strict(*s)),
+            Constraint::MeanBetween { min, max } => write!(f, "mean between {} and {}", min, max),
+            Constraint::SumEqual(v) => write!(f, "sum = {}", v),
+            Constraint::StdBelow(v) => write!(f, "std < {}", v),
+            Constraint::MedianBetween { min, max } => {
+                write!(f, "median between {} and {}", min, max)
+            }
+            Constraint::MinBetween { min, max } => write!(f, "min between {} and {}", min, max),
+            Constraint::MaxBetween { min, max } => write!(f, "max between {} and {}", min, max),
+            Constraint::NullRatioAtMost(v) => write!(f, "null ratio <= {}", v),
+            Constraint::DistinctCountBetween { min, max } => {
+                write!(f, "distinct count between {} and {}", min, max)
+            }
+            Constraint::RowCountBetween { min, max } => {
+                write!(f, "row count between {} and {}", min, max)
+            }
+            Constraint::All(constraints) => write_joined(f, constraints, " and ", "true"),
+            Constraint::Any(constraints) => write_joined(f, constraints, " or ", "false"),
+            Constraint::Not(inner) => match inner.as_ref() {
+                Constraint::NotNull => write!(f, "null"),
+                Constraint::All(_) | Constraint::Any(_) => write!(f, "not ({})", inner),
+                _ => write!(f, "not {}", inner),
+            },
+        }
+    }
+}
+
+/// Writes `constraints` separated by `separator`, parenthesizing nested
+/// `All`/`Any` so the grouping survives; `empty` stands for no constraints.
+fn write_joined(
+    f: &mut std::fmt::Formatter<'_>,
+    constraints: &[Constraint],
+    separator: &str,
+    empty: &str,
+) -> std::fmt::Result {
+    if constraints.is_empty() {
+        return write!(f, "{}", empty);
+    }
+    for (i, constraint) in constraints.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", separator)?;
+        }
+        match constraint {
+            Constraint::All(_) | Constraint::Any(_) => write!(f, "({})", constraint)?,
+            _ => write!(f, "{}", constraint)?,
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
 pub struct ValidationResult {
     pub column: String,
     pub constraint: String,
     pub passed: bool,
     pub failed_count: usize,
+    /// Number of rows the constraint was evaluated on; for conditional rules
+    /// only the rows matching the condition.
+    pub evaluated_count: usize,
+    /// `failed_count / evaluated_count`, or 0.0 when nothing was evaluated.
+    pub failed_ratio: f64,
+    /// The rule's tolerance, for comparing against `failed_ratio`/`failed_count`.
+    pub tolerance: Option<Tolerance>,
+    pub severity: Severity,
     pub error: Option<String>,
+    /// Indices of failing rows, capped at `ValidationOptions::max_failed_rows`.
+    pub failed_rows: Vec<usize>,
+    /// Rendered failing values (`"null"` for nulls), capped at
+    /// `ValidationOptions::max_sample_values`.
+    pub sample_values: Vec<String>,
+    /// The statistic an aggregate constraint checked, e.g. the column's mean;
+    /// `None` for row-level constraints or when the statistic is undefined.
+    pub observed: Option<f64>,
+}
+
+/// Knobs for a validation run.
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    /// Maximum number of failing row indices recorded per rule.
+    pub max_failed_rows: usize,
+    /// Maximum number of failing values sampled per rule.
+    pub max_sample_values: usize,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        ValidationOptions {
+            max_failed_rows: 100,
+            max_sample_values: 10,
+        }
+    }
+}
+
+impl Constraint {
+    /// Whether the constraint can be nested in `All`, `Any` or `Not`, or used
+    /// as a condition: it reads only one column, and each row on its own.
+    fn is_composable(&self) -> bool {
+        !matches!(
+            self,
+            Constraint::Unique
+                | Constraint::UniqueWith(_)
+                | Constraint::ExactlyOneNotNull(_)
+                | Constraint::GreaterThanColumn(_)
+                | Constraint::GreaterThanOrEqualColumn(_)
+                | Constraint::LessThanColumn(_)
+                | Constraint::LessThanOrEqualColumn(_)
+                | Constraint::EqualColumn(_)
+                | Constraint::Increasing { .. }
+                | Constraint::Decreasing { .. }
+        ) && !self.is_aggregate()
+    }
+
+    /// Whether the constraint checks a statistic of the whole column rather
+    /// than each row.
+    fn is_aggregate(&self) -> bool {
+        matches!(
+            self,
+            Constraint::MeanBetween { .. }
+                | Constraint::SumEqual(_)
+                | Constraint::StdBelow(_)
+                | Constraint::MedianBetween { .. }
+                | Constraint::MinBetween { .. }
+                | Constraint::MaxBetween { .. }
+                | Constraint::NullRatioAtMost(_)
+                | Constraint::DistinctCountBetween { .. }
+                | Constraint::RowCountBetween { .. }
+        )
+    }
 }
 
 impl Rule {
@@ -45,28 +574,130 @@ impl Rule {
         Rule {
             column: column.to_string(),
             constraint,
+            condition: None,
+            tolerance: None,
+            severity: Severity::Error,
+            null_policy: NullPolicy::Fail,
+        }
+    }
+
+    pub fn severity(mut self, severity: Severity) -> Rule {
+        self.severity = severity;
+        self
+    }
+
+    pub fn null_policy(mut self, null_policy: NullPolicy) -> Rule {
+        self.null_policy = null_policy;
+        self
+    }
+
+    /// Passes the rule when at least `fraction` of evaluated rows pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is not between 0.0 and 1.0.
+    pub fn mostly(mut self, fraction: f64) -> Rule {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "mostly must be between 0.0 and 1.0, got {} for the rule on column '{}'",
+            fraction,
+            self.column
+        );
+        self.tolerance = Some(Tolerance::Mostly(fraction));
+        self
+    }
+
+    /// Passes the rule when at most `count` rows fail.
+    pub fn max_failures(mut self, count: usize) -> Rule {
+        self.tolerance = Some(Tolerance::MaxFailures(count));
+        self
+    }
+
+    /// Applies the rule only to rows where `constraint` passes on `column`,
+    /// e.g. `Rule::new("zip", ..).when("country", Constraint::InSet(..))`.
+    /// Constraints that compare rows or columns, or check the whole column,
+    /// are rejected when the rule runs.
+    pub fn when(mut self, column: &str, constraint: Constraint) -> Rule {
+        self.condition = Some(Condition {
+            column: column.to_string(),
+            constraint,
+        });
+        self
+    }
+
+    fn describe(&self) -> String {
+        match &self.condition {
+            Some(condition) => format!(
+                "{} when '{}' {}",
+                self.constraint, condition.column, condition.constraint
+            ),
+            None => self.constraint.to_string(),
+        }
+    }
+
+    /// All columns the rule reads: its own column first, then any columns
+    /// referenced by a cross-column constraint.
+    pub fn columns(&self) -> Vec<&str> {
+        let mut columns = vec![self.column.as_str()];
+        match &self.constraint {
+            Constraint::GreaterThanColumn(other)
+            | Constraint::GreaterThanOrEqualColumn(other)
+            | Constraint::LessThanColumn(other)
+            | Constraint::LessThanOrEqualColumn(other)
+            | Constraint::EqualColumn(other) => columns.push(other),
+            Constraint::UniqueWith(others) | Constraint::ExactlyOneNotNull(others) => {
+                columns.extend(others.iter().map(String::as_str))
+            }
+            _ => {}
         }
+        columns
     }
 }
 
 impl ValidationResult {
     pub fn passed(rule: &Rule) -> Self {
         ValidationResult {
-            column: rule.column.clone(),
-            constraint: format!("{:?}", rule.constraint),
+            column: rule.columns().join(", "),
+            constraint: rule.describe(),
             passed: true,
             failed_count: 0,
+            evaluated_count: 0,
+            failed_ratio: 0.0,
+            tolerance: rule.tolerance,
+            severity: rule.severity,
             error: None,
+            failed_rows: Vec::new(),
+            sample_values: Vec::new(),
+            observed: None,
         }
     }
 
     pub fn failed(rule: &Rule, failed_count: usize, error: &str) -> Self {
         ValidationResult {
-            column: rule.column.clone(),
-            constraint: format!("{:?}", rule.constraint),
+            column: rule.columns().join(", "),
+            constraint: rule.describe(),
             passed: false,
             failed_count,
+            evaluated_count: 0,
+            failed_ratio: 0.0,
+            tolerance: rule.tolerance,
+            severity: rule.severity,
             error: Some(error.to_string()),
+            failed_rows: Vec::new(),
+            sample_values: Vec::new(),
+            observed: None,
+        }
+    }
+}
+
+impl ValidationResult {
+    /// Short status label: `PASS`, or `FAIL`/`WARN`/`INFO` by severity.
+    pub fn status(&self) -> &'static str {
+        match (self.passed, self.severity) {
+            (true, _) => "PASS",
+            (false, Severity::Error) => "FAIL",
+            (false, Severity::Warning) => "WARN",
+            (false, Severity::Info) => "INFO",
         }
     }
 }
@@ -74,266 +705,737 @@ impl ValidationResult {
 impl std::fmt::Display for ValidationResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.passed {
-            write!(f, "PASS: column '{}' — {}", self.column, self.constraint)
-        } else {
             write!(
                 f,
-                "FAIL: column '{}' — {} — {} values failed: {}",
+                "{}: column '{}' — {}",
+                self.status(),
                 self.column,
-                self.constraint,
-                self.failed_count,
+                self.constraint
+            )?;
+            if let Some(observed) = self.observed {
+                write!(f, " (observed {})", observed)?;
+            } else if let Some(tolerance) = &self.tolerance {
+                write!(
+                    f,
+                    " ({} of {} values failed, {})",
+                    self.failed_count, self.evaluated_count, tolerance
+                )?;
+            }
+            return Ok(());
+        }
+        write!(
+            f,
+            "{}: column '{}' — {} — ",
+            self.status(),
+            self.column,
+            self.constraint
+        )?;
+        if let Some(observed) = self.observed {
+            return write!(
+                f,
+                "observed {}: {}",
+                observed,
                 self.error.as_deref().unwrap_or("unknown error")
-            )
+            );
+        }
+        // nothing failed, so the rule couldn't run, e.g. a missing column
+        if self.failed_count == 0 {
+            return write!(f, "{}", self.error.as_deref().unwrap_or("unknown error"));
+        }
+        if self.evaluated_count > 0 {
+            write!(f, "{} of {} ", self.failed_count, self.evaluated_count)?;
+        } else {
+            write!(f, "{} ", self.failed_count)?;
+        }
+        write!(
+            f,
+            "values failed: {}",
+            self.error.as_deref().unwrap_or("unknown error")
+        )?;
+        if !self.failed_rows.is_empty() {
+            let rows: Vec<String> = self.failed_rows.iter().map(|r| r.to_string()).collect();
+            let more = if self.failed_count > self.failed_rows.len() {
+                ", ..."
+            } else {
+                ""
+            };
+            write!(f, " (rows: {}{})", rows.join(", "), more)?;
+        }
+        if !self.sample_values.is_empty() {
+            write!(f, " (sample: {})", self.sample_values.join(", "))?;
+        }
+        if let Some(tolerance) = &self.tolerance {
+            write!(
+                f,
+                " ({:.2}% failed, {})",
+                self.failed_ratio * 100.0,
+                tolerance
+            )?;
         }
+        Ok(())
     }
 }
 
-fn validate_col_with_rule(
-    column: &Column,
+/// Compiled regex patterns, keyed by pattern source.
+type Regexes = HashMap<String, Regex>;
+
+/// Rules prepared for evaluation. Every `MatchesRegex` pattern (in the
+/// constraint or the condition) is compiled once, so a bad pattern is caught
+/// before any data is read and the compiled regex is reused on every run.
+#[derive(Debug, Clone)]
+pub struct CompiledRules {
+    rules: Vec<Rule>,
+    regexes: Regexes,
+    /// Per rule, why it can't run; only set by `compile_lenient`.
+    errors: Vec<Option<String>>,
+}
+
+impl CompiledRules {
+    /// Compiles the rules, failing on the first invalid pattern.
+    pub fn new(rules: &[Rule]) -> Result<CompiledRules, ValidationError> {
+        let mut regexes = Regexes::new();
+        for (index, rule) in rules.iter().enumerate() {
+            compile_patterns(index, rule, &mut regexes)?;
+        }
+        Ok(CompiledRules {
+            rules: rules.to_vec(),
+            regexes,
+            errors: vec![None; rules.len()],
+        })
+    }
+
+    /// Compiles the rules, recording invalid patterns against their rule
+    /// instead of failing, so the other rules can still run.
+    fn compile_lenient(rules: &[Rule]) -> CompiledRules {
+        let mut regexes = Regexes::new();
+        let errors = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                compile_patterns(index, rule, &mut regexes)
+                    .err()
+                    .map(|e| e.to_string())
+            })
+            .collect();
+        CompiledRules {
+            rules: rules.to_vec(),
+            regexes,
+            errors,
+        }
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    fn result(
+        &self,
+        data: &Dataset,
+        index: usize,
+        options: &ValidationOptions,
+    ) -> ValidationResult {
+        let rule = &self.rules[index];
+        if let Some(error) = &self.errors[index] {
+            return ValidationResult::failed(rule, 0, error);
+        }
+        validate_rule(data, index, rule, &self.regexes, options)
+            .unwrap_or_else(|e| ValidationResult::failed(rule, 0, &e.to_string()))
+    }
+}
+
+fn compile_patterns(
+    index: usize,
     rule: &Rule,
-) -> Result<ValidationResult, ValidationError> {
-    match &rule.constraint {
-        Constraint::NotNull => Ok(check_not_null(column, rule)),
-        Constraint::GreaterThan(v) => Ok(check_greater_than(column, *v, rule)),
-        Constraint::GreaterThanOrEqual(v) => Ok(check_greater_than_or_equal(column, *v, rule)),
-        Constraint::LessThan(v) => Ok(check_less_than(column, *v, rule)),
-        Constraint::LessThanOrEqual(v) => Ok(check_less_than_or_equal(column, *v, rule)),
-        Constraint::Equal(v) => Ok(check_equal(column, *v, rule)),
-        Constraint::Between { min, max } => Ok(check_between(column, *min, *max, rule)),
-        Constraint::MatchesRegex(p) => Ok(check_matches_regex(column, p, rule)),
-        Constraint::Contains(p) => Ok(check_contains(column, p, rule)),
-        Constraint::StartsWith(p) => Ok(check_starts_with(column, p, rule)),
-        Constraint::EndsWith(p) => Ok(check_ends_with(column, p, rule)),
-        Constraint::LengthBetween { min, max } => {
-            Ok(check_length_between(column, *min, *max, rule))
+    regexes: &mut Regexes,
+) -> Result<(), ValidationError> {
+    let mut patterns = Vec::new();
+    regex_patterns(&rule.constraint, &mut patterns);
+    if let Some(condition) = &rule.condition {
+        regex_patterns(&condition.constraint, &mut patterns);
+    }
+    for pattern in patterns {
+        if regexes.contains_key(pattern) {
+            continue;
         }
-        Constraint::Unique => Ok(check_unique(column, rule)),
-        Constraint::InSet(other) => Ok(check_is_in_set(column, other, rule)),
+        let regex = Regex::new(pattern).map_err(|e| ValidationError::InvalidRegex {
+            index,
+            column: rule.column.clone(),
+            pattern: pattern.clone(),
+            message: e.to_string(),
+        })?;
+        regexes.insert(pattern.clone(), regex);
     }
+    Ok(())
 }
 
-pub fn validate(data: &Dataset, rules: &[Rule]) -> Vec<ValidationResult> {
-    rules
-        .iter()
-        .map(|rule| {
-            let col = data.get_column_by_name(&rule.column);
-            match col {
-                Some(col) => validate_col_with_rule(col, rule)
-                    .unwrap_or_else(|e| ValidationResult::failed(rule, 0, &e.to_string())),
-                None => {
-                    let error = ValidationError::ColumnNotFound {
-                        name: rule.column.clone(),
-                    };
-                    ValidationResult::failed(rule, 0, &error.to_string())
-                }
+/// Collects the `MatchesRegex` patterns in `constraint`, including nested ones.
+fn regex_patterns<'a>(constraint: &'a Constraint, patterns: &mut Vec<&'a String>) {
+    match constraint {
+        Constraint::MatchesRegex(pattern) => patterns.push(pattern),
+        Constraint::All(constraints) | Constraint::Any(constraints) => {
+            for constraint in constraints {
+                regex_patterns(constraint, patterns);
             }
-        })
-        .collect()
+        }
+        Constraint::Not(constraint) => regex_patterns(constraint, patterns),
+        _ => {}
+    }
 }
 
-fn check_not_null(col: &Column, rule: &Rule) -> ValidationResult {
-    let failed = col.null_count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(rule, failed, "null values found")
+fn validate_rule(
+    data: &Dataset,
+    index: usize,
+    rule: &Rule,
+    regexes: &Regexes,
+    options: &ValidationOptions,
+) -> Result<ValidationResult, ValidationError> {
+    check::check_rule(index, rule, |name| {
+        data.get_column_by_name(name).map(Column::dtype)
+    })?;
+    let (columns, rows) = select_rows(data, rule, regexes)?;
+    if rule.constraint.is_aggregate() {
+        let aggregate = Aggregate::of(&rule.constraint, &columns[0]);
+        return Ok(aggregate_result(rule, &aggregate));
     }
+    let columns: Vec<&Column> = columns.iter().map(AsRef::as_ref).collect();
+    let mask = evaluate(&columns, &rule.constraint, regexes);
+    Ok(check_mask(&columns, mask, rows.as_deref(), rule, options))
 }
 
-fn check_greater_than(col: &Column, value: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .gt(value)
+/// A rule's columns (in `Rule::columns` order) narrowed to the rows matching
+/// its condition, plus the dataset row behind each selected row for
+/// conditional rules.
+type Selection<'a> = (Vec<Cow<'a, Column>>, Option<Vec<usize>>);
+
+fn select_rows<'a>(
+    data: &'a Dataset,
+    rule: &Rule,
+    regexes: &Regexes,
+) -> Result<Selection<'a>, ValidationError> {
+    let columns = rule
+        .columns()
+        .into_iter()
+        .map(|name| {
+            data.get_column_by_name(name)
+                .ok_or_else(|| ValidationError::ColumnNotFound {
+                    name: name.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let Some(condition) = &rule.condition else {
+        return Ok((columns.into_iter().map(Cow::Borrowed).collect(), None));
+    };
+
+    let condition_column = data.get_column_by_name(&condition.column).ok_or_else(|| {
+        ValidationError::ColumnNotFound {
+            name: condition.column.clone(),
+        }
+    })?;
+    let selected: Vec<usize> = evaluate(&[condition_column], &condition.constraint, regexes)
+        .true_indices()
+        .collect();
+    let filtered = columns
         .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(rule, failed, &format!("values not greater than {}", value))
-    }
+        .map(|c| Cow::Owned(c.take(&selected)))
+        .collect();
+    Ok((filtered, Some(selected)))
+}
+
+pub fn validate(data: &Dataset, rules: &[Rule]) -> Report {
+    validate_with_options(data, rules, &ValidationOptions::default())
 }
 
-fn check_greater_than_or_equal(col: &Column, value: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .ge(value)
+/// Validates `data` against `rules`. A rule with an invalid regex pattern
+/// fails with that error instead of aborting the run; use `CompiledRules` to
+/// reject such rules up front.
+pub fn validate_with_options(
+    data: &Dataset,
+    rules: &[Rule],
+    options: &ValidationOptions,
+) -> Report {
+    validate_compiled(data, &CompiledRules::compile_lenient(rules), options)
+}
+
+pub fn validate_compiled(
+    data: &Dataset,
+    rules: &CompiledRules,
+    options: &ValidationOptions,
+) -> Report {
+    let start = Instant::now();
+    #[cfg(feature = "parallel")]
+    let results = parallel::rule_results(data, rules, options);
+    #[cfg(not(feature = "parallel"))]
+    let results: Vec<ValidationResult> = (0..rules.rules.len())
+        .map(|index| rules.result(data, index, options))
+        .collect();
+    let mut findings = coercion_results(data, options);
+    findings.extend(overlong_result(data, options));
+    Report::new(results, data.shape(), start.elapsed()).with_load_findings(findings)
+}
+
+/// One failed type-conformance result per column that had cells coerced to
+/// null while loading, in header order.
+fn coercion_results(data: &Dataset, options: &ValidationOptions) -> Vec<ValidationResult> {
+    let rows = data.shape().0;
+    data.headers
         .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values not greater than or equal to {}", value),
-        )
+        .filter_map(|header| {
+            let mut coerced: Vec<&Coercion> = data
+                .coercions
+                .iter()
+                .filter(|c| &c.column == header)
+                .collect();
+            let expected = coerced.first()?.expected.clone();
+            coerced.sort_by_key(|c| c.row);
+            Some(type_conformance_result(
+                header,
+                &expected,
+                coerced.len(),
+                rows,
+                coerced
+                    .iter()
+                    .take(options.max_failed_rows)
+                    .map(|c| c.row)
+                    .collect(),
+                coerced
+                    .iter()
+                    .take(options.max_sample_values)
+                    .map(|c| coerced_value(c.value.as_deref()))
+                    .collect(),
+            ))
+        })
+        .collect()
+}
+
+fn type_conformance_result(
+    column: &str,
+    expected: &DataType,
+    failed_count: usize,
+    evaluated_count: usize,
+    failed_rows: Vec<usize>,
+    sample_values: Vec<String>,
+) -> ValidationResult {
+    ValidationResult {
+        column: column.to_string(),
+        constraint: format!("parses as {:?}", expected),
+        passed: false,
+        failed_count,
+        evaluated_count,
+        failed_ratio: failed_ratio(failed_count, evaluated_count),
+        tolerance: None,
+        severity: Severity::Error,
+        error: Some(format!("values could not be parsed as {:?}", expected)),
+        failed_rows,
+        sample_values,
+        observed: None,
     }
 }
 
-fn check_less_than(col: &Column, value: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .lt(value)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(rule, failed, &format!("values not less than {}", value))
+/// One failed row-length result when rows had cells past the header while
+/// loading.
+fn overlong_result(data: &Dataset, options: &ValidationOptions) -> Option<ValidationResult> {
+    let overlong = &data.overlong_rows;
+    if overlong.is_empty() {
+        return None;
     }
+    Some(row_length_result(
+        overlong.len(),
+        data.shape().0,
+        overlong
+            .iter()
+            .take(options.max_failed_rows)
+            .map(|r| r.row)
+            .collect(),
+        overlong
+            .iter()
+            .take(options.max_sample_values)
+            .map(|r| overlong_value(&r.extra))
+            .collect(),
+    ))
 }
 
-fn check_less_than_or_equal(col: &Column, value: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .le(value)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values not less than or equal to {}", value),
-        )
+/// Not tied to a column, so reported under `*`.
+fn row_length_result(
+    failed_count: usize,
+    evaluated_count: usize,
+    failed_rows: Vec<usize>,
+    sample_values: Vec<String>,
+) -> ValidationResult {
+    ValidationResult {
+        column: "*".to_string(),
+        constraint: "row length = header length".to_string(),
+        passed: false,
+        failed_count,
+        evaluated_count,
+        failed_ratio: failed_ratio(failed_count, evaluated_count),
+        tolerance: None,
+        severity: Severity::Error,
+        error: Some(
+            "rows had more cells than the header; the extra cells were dropped".to_string(),
+        ),
+        failed_rows,
+        sample_values,
+        observed: None,
     }
 }
 
-fn check_equal(col: &Column, value: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .equal(value)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(rule, failed, &format!("values not equal to {}", value))
+/// Sample value for an overlong row: the cells that were dropped.
+fn overlong_value(extra: &[String]) -> String {
+    match extra {
+        [cell] => cell.clone(),
+        cells => format!("({})", cells.join(", ")),
     }
 }
 
-fn check_between(col: &Column, min: f64, max: f64, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .between(min, max)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values not between {} and {}", min, max),
-        )
+/// Sample value for a coerced cell; `None` means the row was too short.
+fn coerced_value(value: Option<&str>) -> String {
+    value.unwrap_or("<missing>").to_string()
+}
+
+/// `constraint_mask`, split across threads with the `parallel` feature.
+fn evaluate(columns: &[&Column], constraint: &Constraint, regexes: &Regexes) -> Mask {
+    #[cfg(feature = "parallel")]
+    return parallel::chunked_mask(columns, constraint, regexes);
+    #[cfg(not(feature = "parallel"))]
+    constraint_mask(columns, constraint, regexes)
+}
+
+/// Evaluates a constraint row by row. A row passes only when it is true;
+/// false and null (null value or unsupported type) rows fail.
+///
+/// `columns` are the rule's columns in `Rule::columns` order; `regexes` holds
+/// the compiled `MatchesRegex` patterns.
+fn constraint_mask(columns: &[&Column], constraint: &Constraint, regexes: &Regexes) -> Mask {
+    let col = columns[0];
+    match constraint {
+        Constraint::NotNull => col.is_null().into_iter().map(|n| Some(!n)).collect(),
+        Constraint::Unique => col
+            .duplicated()
+            .into_iter()
+            .zip(col.is_null())
+            .map(|(duplicated, null)| (!null).then_some(!duplicated))
+            .collect(),
+        Constraint::GreaterThan(v) => col.gt(*v),
+        Constraint::GreaterThanOrEqual(v) => col.ge(*v),
+        Constraint::LessThan(v) => col.lt(*v),
+        Constraint::LessThanOrEqual(v) => col.le(*v),
+        Constraint::Equal(v) => col.equal(*v),
+        Constraint::Between { min, max } => col.between(*min, *max),
+        Constraint::MaxScale(max) => col
+            .scale()
+            .into_iter()
+            .map(|scale| scale.map(|s| s <= *max))
+            .collect(),
+        Constraint::Precision { precision, scale } => col.fits_precision(*precision, *scale),
+        Constraint::MatchesRegex(p) => match regexes.get(p) {
+            Some(re) => col.is_match(re),
+            None => Mask::nulls(col.len()),
+        },
+        Constraint::Contains(p) => col.contains(p),
+        Constraint::StartsWith(p) => col.starts_with(p),
+        Constraint::EndsWith(p) => col.ends_with(p),
+        Constraint::LengthBetween { min, max } => col
+            .str_length()
+            .into_iter()
+            .map(|opt| opt.map(|v| (v >= *min) && (v <= *max)))
+            .collect(),
+        Constraint::InSet(other) => col.is_in(other),
+        Constraint::GreaterThanColumn(_) => compare_columns(columns, Ordering::is_gt),
+        Constraint::GreaterThanOrEqualColumn(_) => compare_columns(columns, Ordering::is_ge),
+        Constraint::LessThanColumn(_) => compare_columns(columns, Ordering::is_lt),
+        Constraint::LessThanOrEqualColumn(_) => compare_columns(columns, Ordering::is_le),
+        Constraint::EqualColumn(_) => compare_columns(columns, Ordering::is_eq),
+        Constraint::UniqueWith(_) => {
+            let mut seen = HashSet::new();
+            (0..col.len())
+                .map(|row| {
+                    // a key with a null is left to the null policy
+                    let key: Option<Vec<String>> =
+                        columns.iter().map(|c| c.key_value(row)).collect();
+                    key.map(|key| seen.insert(key))
+                })
+                .collect()
+        }
+        Constraint::ExactlyOneNotNull(_) => (0..col.len())
+            .map(|row| {
+                let not_null = columns
+                    .iter()
+                    .filter(|c| c.format_value(row).is_some())
+                    .count();
+                Some(not_null == 1)
+            })
+            .collect(),
+        Constraint::DateBetween { min, max } => col.between_dates(*min, *max),
+        Constraint::NotInFuture => col.not_in_future(),
+        Constraint::Freshness {
+            max_age_secs,
+            reference,
+        } => {
+            let reference = reference.unwrap_or_else(|| Utc::now().fixed_offset());
+            let cutoff = i64::try_from(*max_age_secs)
+                .ok()
+                .and_then(TimeDelta::try_seconds)
+                .and_then(|max_age| reference.checked_sub_signed(max_age));
+            match cutoff {
+                Some(cutoff) => col.ge_datetime(cutoff),
+                // older than any representable time: only nulls fail
+                None => col.ge_datetime(DateTime::<Utc>::MIN_UTC.fixed_offset()),
+            }
+        }
+        Constraint::IsWeekday => col.is_weekend().not(),
+        Constraint::IsWeekend => col.is_weekend(),
+        Constraint::All(constraints) => constraints
+            .iter()
+            .fold(Mask::filled(col.len(), true), |mask, c| {
+                mask.and(&constraint_mask(columns, c, regexes))
+            }),
+        Constraint::Any(constraints) => constraints
+            .iter()
+            .fold(Mask::filled(col.len(), false), |mask, c| {
+                mask.or(&constraint_mask(columns, c, regexes))
+            }),
+        Constraint::Not(constraint) => constraint_mask(columns, constraint, regexes).not(),
+        Constraint::Increasing { .. } | Constraint::Decreasing { .. } => {
+            let accept = ordering(constraint).expect("ordering constraint");
+            monotonic_mask(col, accept)
+        }
+        Constraint::MeanBetween { .. }
+        | Constraint::SumEqual(_)
+        | Constraint::StdBelow(_)
+        | Constraint::MedianBetween { .. }
+        | Constraint::MinBetween { .. }
+        | Constraint::MaxBetween { .. }
+        | Constraint::NullRatioAtMost(_)
+        | Constraint::DistinctCountBetween { .. }
+        | Constraint::RowCountBetween { .. } => {
+            unreachable!("aggregate constraints are checked by `aggregate_result`")
+        }
     }
 }
 
-fn check_matches_regex(col: &Column, pattern: &str, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .matches_regex(pattern)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values don't match regex '{}'", pattern),
-        )
+/// How an ordering constraint accepts a value compared with the previous one.
+fn ordering(constraint: &Constraint) -> Option<fn(Ordering) -> bool> {
+    match constraint {
+        Constraint::Increasing { strict: false } => Some(Ordering::is_ge),
+        Constraint::Increasing { strict: true } => Some(Ordering::is_gt),
+        Constraint::Decreasing { strict: false } => Some(Ordering::is_le),
+        Constraint::Decreasing { strict: true } => Some(Ordering::is_lt),
+        _ => None,
     }
 }
 
-fn check_contains(col: &Column, pattern: &str, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .contains(pattern)
+/// Compares every non-null value with the previous non-null value; the
+/// first one passes and nulls fail.
+fn monotonic_mask(col: &Column, accept: fn(Ordering) -> bool) -> Mask {
+    let not_null: Vec<usize> = col
+        .is_null()
         .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(rule, failed, &format!("values don't contain '{}'", pattern))
+        .enumerate()
+        .filter(|(_, null)| !**null)
+        .map(|(row, _)| row)
+        .collect();
+    let mut mask = Mask::nulls(col.len());
+    let Some((&first, rest)) = not_null.split_first() else {
+        return mask;
+    };
+    mask.set(first, Some(true));
+    let current = col.take(rest);
+    let previous = col.take(&not_null[..rest.len()]);
+    for (&row, ord) in rest.iter().zip(current.compare(&previous)) {
+        mask.set(row, ord.map(accept));
     }
+    mask
 }
 
-fn check_starts_with(col: &Column, pattern: &str, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .starts_with(pattern)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values don't start with '{}'", pattern),
-        )
+fn compare_columns(columns: &[&Column], accept: fn(Ordering) -> bool) -> Mask {
+    columns[0]
+        .compare(columns[1])
+        .into_iter()
+        .map(|ord| ord.map(accept))
+        .collect()
+}
+
+fn failure_message(constraint: &Constraint) -> String {
+    match constraint {
+        Constraint::NotNull => "null values found".to_string(),
+        Constraint::Unique => "column values are not unique".to_string(),
+        Constraint::GreaterThan(v) => format!("values not greater than {}", v),
+        Constraint::GreaterThanOrEqual(v) => {
+            format!("values not greater than or equal to {}", v)
+        }
+        Constraint::LessThan(v) => format!("values not less than {}", v),
+        Constraint::LessThanOrEqual(v) => format!("values not less than or equal to {}", v),
+        Constraint::Equal(v) => format!("values not equal to {}", v),
+        Constraint::Between { min, max } => format!("values not between {} and {}", min, max),
+        Constraint::MaxScale(max) => format!("values with more than {} decimal places", max),
+        Constraint::Precision { precision, scale } => {
+            format!("values that don't fit NUMERIC({}, {})", precision, scale)
+        }
+        Constraint::MatchesRegex(p) => format!("values don't match regex '{}'", p),
+        Constraint::Contains(p) => format!("values don't contain '{}'", p),
+        Constraint::StartsWith(p) => format!("values don't start with '{}'", p),
+        Constraint::EndsWith(p) => format!("values don't end with '{}'", p),
+        Constraint::LengthBetween { min, max } => {
+            format!("string lengths not between {} and {}", min, max)
+        }
+        Constraint::InSet(other) => format!("column values are not in set: {:?}", other),
+        Constraint::GreaterThanColumn(c) => format!("values not greater than column '{}'", c),
+        Constraint::GreaterThanOrEqualColumn(c) => {
+            format!("values not greater than or equal to column '{}'", c)
+        }
+        Constraint::LessThanColumn(c) => format!("values not less than column '{}'", c),
+        Constraint::LessThanOrEqualColumn(c) => {
+            format!("values not less than or equal to column '{}'", c)
+        }
+        Constraint::EqualColumn(c) => format!("values not equal to column '{}'", c),
+        Constraint::UniqueWith(_) => "column combinations are not unique".to_string(),
+        Constraint::ExactlyOneNotNull(_) => "rows without exactly one non-null value".to_string(),
+        Constraint::DateBetween { min, max } => format!("dates not between {} and {}", min, max),
+        Constraint::NotInFuture => "values in the future".to_string(),
+        Constraint::Freshness {
+            max_age_secs,
+            reference,
+        } => match reference {
+            Some(reference) => format!(
+                "values more than {}s older than {}",
+                max_age_secs,
+                reference.to_rfc3339()
+            ),
+            None => format!("values more than {}s old", max_age_secs),
+        },
+        Constraint::IsWeekday => "dates not on a weekday".to_string(),
+        Constraint::IsWeekend => "dates not on a weekend".to_string(),
+        Constraint::Increasing { strict } => {
+            format!(
+                "values not {}increasing",
+                if *strict { "strictly " } else { "" }
+            )
+        }
+        Constraint::Decreasing { strict } => {
+            format!(
+                "values not {}decreasing",
+                if *strict { "strictly " } else { "" }
+            )
+        }
+        Constraint::All(_) | Constraint::Any(_) | Constraint::Not(_) => {
+            format!("values not satisfying: {}", constraint)
+        }
+        Constraint::MeanBetween { min, max } => format!("mean not between {} and {}", min, max),
+        Constraint::SumEqual(v) => format!("sum not equal to {}", v),
+        Constraint::StdBelow(v) => format!("std not below {}", v),
+        Constraint::MedianBetween { min, max } => {
+            format!("median not between {} and {}", min, max)
+        }
+        Constraint::MinBetween { min, max } => format!("min not between {} and {}", min, max),
+        Constraint::MaxBetween { min, max } => format!("max not between {} and {}", min, max),
+        Constraint::NullRatioAtMost(v) => format!("null ratio above {}", v),
+        Constraint::DistinctCountBetween { min, max } => {
+            format!("distinct count not between {} and {}", min, max)
+        }
+        Constraint::RowCountBetween { min, max } => {
+            format!("row count not between {} and {}", min, max)
+        }
     }
 }
 
-fn check_ends_with(col: &Column, pattern: &str, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .ends_with(pattern)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed == 0 {
-        ValidationResult::passed(rule)
-    } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("values don't end with '{}'", pattern),
-        )
+/// Applies the rule's null policy to `mask`, returning a mask whose not-true
+/// rows are exactly the failing ones, and the number of evaluated rows.
+fn resolve_nulls(mask: Mask, null_policy: NullPolicy) -> (Mask, usize) {
+    match null_policy {
+        NullPolicy::Fail => {
+            let len = mask.len();
+            (mask, len)
+        }
+        NullPolicy::Pass => (mask.fill_null(true), mask.len()),
+        NullPolicy::Exclude => (mask.fill_null(true), mask.len() - mask.count_null()),
     }
 }
 
-fn check_length_between(col: &Column, min: usize, max: usize, rule: &Rule) -> ValidationResult {
-    let failed = col
-        .str_length()
-        .iter()
-        .map(|opt| opt.is_some_and(|v| (v >= min) && (v <= max)))
-        .filter(|v| !v)
-        .count();
-    if failed == 0 {
+/// Counts the failing rows of `mask` and records their indices and values.
+/// Multi-column samples are rendered as a tuple of the involved values.
+///
+/// `rows` maps mask positions back to dataset rows when the columns were
+/// filtered by a condition.
+fn check_mask(
+    columns: &[&Column],
+    mask: Mask,
+    rows: Option<&[usize]>,
+    rule: &Rule,
+    options: &ValidationOptions,
+) -> ValidationResult {
+    let (mask, evaluated_count) = resolve_nulls(mask, rule.null_policy);
+    let failed_count = mask.len() - mask.count_true();
+    let failed_rows = mask
+        .not_true_indices()
+        .take(options.max_failed_rows)
+        .map(|row| rows.map_or(row, |rows| rows[row]))
+        .collect();
+    let sample_values = mask
+        .not_true_indices()
+        .take(options.max_sample_values)
+        .map(|row| format_row(columns, row))
+        .collect();
+
+    rule_result(
+        rule,
+        failed_count,
+        evaluated_count,
+        failed_rows,
+        sample_values,
+    )
+}
+
+/// Builds a rule's result from its failure counts, applying the tolerance.
+fn rule_result(
+    rule: &Rule,
+    failed_count: usize,
+    evaluated_count: usize,
+    failed_rows: Vec<usize>,
+    sample_values: Vec<String>,
+) -> ValidationResult {
+    let passed = match &rule.tolerance {
+        Some(tolerance) => tolerance.allows(failed_count, evaluated_count),
+        None => failed_count == 0,
+    };
+    let base = if passed {
         ValidationResult::passed(rule)
     } else {
-        ValidationResult::failed(
-            rule,
-            failed,
-            &format!("string lengths not between {} and {}", min, max),
-        )
+        ValidationResult::failed(rule, failed_count, &failure_message(&rule.constraint))
+    };
+    ValidationResult {
+        failed_count,
+        evaluated_count,
+        failed_ratio: failed_ratio(failed_count, evaluated_count),
+        failed_rows,
+        sample_values,
+        ..base
     }
 }
 
-fn check_is_in_set(col: &Column, other: &InSetValues, rule: &Rule) -> ValidationResult {
-    let failed_count = col
-        .is_in(other)
-        .iter()
-        .filter(|v| !matches!(v, Some(true)))
-        .count();
-    if failed_count == 0 {
-        ValidationResult::passed(rule)
+fn failed_ratio(failed_count: usize, evaluated_count: usize) -> f64 {
+    if evaluated_count == 0 {
+        0.0
     } else {
-        ValidationResult::failed(
-            rule,
-            failed_count,
-            &format!("column values are not in set: {:?}", other),
-        )
+        failed_count as f64 / evaluated_count as f64
     }
 }
 
-fn check_unique(col: &Column, rule: &Rule) -> ValidationResult {
-    let failed_count = col.duplicates_count();
-    if failed_count == 0 {
-        ValidationResult::passed(rule)
+fn format_row(columns: &[&Column], row: usize) -> String {
+    let values: Vec<Option<String>> = columns.iter().map(|c| c.format_value(row)).collect();
+    format_values(&values)
+}
+
+/// Renders one row's values for samples: the bare value for single-column
+/// rules, `(a, b)` otherwise, with nulls as `null`.
+fn format_values(values: &[Option<String>]) -> String {
+    let values: Vec<&str> = values
+        .iter()
+        .map(|v| v.as_deref().unwrap_or("null"))
+        .collect();
+    if values.len() == 1 {
+        values[0].to_string()
     } else {
-        ValidationResult::failed(rule, failed_count, "column values are not unique")
+        format!("({})", values.join(", "))
     }
 }
@@ -0,0 +1,67 @@
+//! Multi-threaded rule evaluation, enabled with the `parallel` feature.
+//!
+//! Rules run concurrently on the rayon thread pool, and row-local constraints
+//! on large columns are additionally split into chunks of `CHUNK_ROWS` rows.
+//! Results come back in the order of the input rules, identical to a
+//! sequential run.
+
+use rayon::prelude::*;
+
+use super::{
+    CompiledRules, Constraint, Regexes, ValidationOptions, ValidationResult, constraint_mask,
+};
+use crate::dataset::{Column, Dataset, Mask};
+
+/// Rows per chunk when a column is split across threads.
+const CHUNK_ROWS: usize = 64 * 1024;
+
+pub(super) fn rule_results(
+    data: &Dataset,
+    rules: &CompiledRules,
+    options: &ValidationOptions,
+) -> Vec<ValidationResult> {
+    (0..rules.rules.len())
+        .into_par_iter()
+        .map(|index| rules.result(data, index, options))
+        .collect()
+}
+
+/// `constraint_mask`, evaluated chunk by chunk in parallel when the columns
+/// are long enough and each row's outcome doesn't depend on other rows.
+pub(super) fn chunked_mask(
+    columns: &[&Column],
+    constraint: &Constraint,
+    regexes: &Regexes,
+) -> Mask {
+    let len = columns[0].len();
+    if len <= CHUNK_ROWS || !is_row_local(constraint) {
+        return constraint_mask(columns, constraint, regexes);
+    }
+    let chunks: Vec<Mask> = (0..len)
+        .step_by(CHUNK_ROWS)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|start| {
+            let rows = start..(start + CHUNK_ROWS).min(len);
+            let chunk: Vec<Column> = columns.iter().map(|c| c.slice(rows.clone())).collect();
+            let chunk: Vec<&Column> = chunk.iter().collect();
+            constraint_mask(&chunk, constraint, regexes)
+        })
+        .collect();
+    // chunks are a whole number of words long, so appending copies words
+    let mut mask = Mask::nulls(0);
+    for chunk in &chunks {
+        mask.extend(chunk);
+    }
+    mask
+}
+
+fn is_row_local(constraint: &Constraint) -> bool {
+    !matches!(
+        constraint,
+        Constraint::Unique
+            | Constraint::UniqueWith(_)
+            | Constraint::Increasing { .. }
+            | Constraint::Decreasing { .. }
+    )
+}
@@ -0,0 +1,436 @@
+//! Validation of data that arrives in row batches.
+//!
+//! Every rule keeps a small partial state (failure count, the earliest failing
+//! rows, for `Unique`/`UniqueWith` the keys seen so far, for ordering
+//! constraints the values at either end, and for aggregate constraints the
+//! running statistics) that is updated batch by batch and can be merged with
+//! the state of the batches that follow, so results match `validate` on the
+//! concatenated data.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, hash_map::Entry},
+    time::Instant,
+};
+
+use super::{
+    Aggregate, CompiledRules, Constraint, NullPolicy, Rule, ValidationOptions, ValidationResult,
+    aggregate_result, check, coerced_value, evaluate, format_values, ordering, overlong_value,
+    resolve_nulls, row_length_result, rule_result, select_rows, type_conformance_result,
+};
+use crate::{
+    dataset::{Coercion, Column, ColumnStats, DataType, Dataset, Mask},
+    report::Report,
+};
+
+/// Keys of a row's rule columns (see `Column::key_value`), for uniqueness.
+type RowKey = Vec<Option<String>>;
+
+#[derive(Debug, Clone, Default)]
+struct Failures {
+    count: usize,
+    /// The earliest failing rows with their rendered values, capped.
+    rows: Vec<(usize, String)>,
+}
+
+impl Failures {
+    /// Records a failure; rows must arrive in increasing order.
+    fn push(&mut self, row: usize, sample: String, keep: usize) {
+        self.count += 1;
+        if self.rows.len() < keep {
+            self.rows.push((row, sample));
+        }
+    }
+
+    fn merge(&mut self, other: Failures, keep: usize) {
+        self.count += other.count;
+        self.rows.extend(other.rows);
+        self.rows.sort_by_key(|(row, _)| *row);
+        self.rows.truncate(keep);
+    }
+
+    fn shifted(mut self, offset: usize) -> Failures {
+        for (row, _) in &mut self.rows {
+            *row += offset;
+        }
+        self
+    }
+
+    fn failed_rows(&self, limit: usize) -> Vec<usize> {
+        self.rows.iter().take(limit).map(|(row, _)| *row).collect()
+    }
+
+    fn sample_values(&self, limit: usize) -> Vec<String> {
+        self.rows
+            .iter()
+            .take(limit)
+            .map(|(_, sample)| sample.clone())
+            .collect()
+    }
+}
+
+#[derive(Clone, Default)]
+struct RuleState {
+    evaluated: usize,
+    failures: Failures,
+    /// First row of every key without nulls seen so far, with its rendered
+    /// values (`Unique` and `UniqueWith` only).
+    seen: HashMap<RowKey, (usize, String)>,
+    /// Row and value of the first non-null value, which had nothing to be
+    /// compared with (`Increasing` and `Decreasing` only).
+    first: Option<(usize, Column)>,
+    /// The last non-null value (`Increasing` and `Decreasing` only).
+    last: Option<Column>,
+    /// The rows seen so far (aggregate constraints only).
+    aggregate: Aggregate,
+    /// Set when the rule cannot run, e.g. a missing column.
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct CoercionState {
+    column: String,
+    expected: DataType,
+    failures: Failures,
+}
+
+/// Validates a dataset batch by batch with the same results as `validate`.
+///
+/// ```ignore
+/// let mut validator = StreamingValidator::new(&rules, ValidationOptions::default());
+/// for batch in batches {
+///     validator.update(&batch);
+/// }
+/// let report = validator.finish();
+/// ```
+pub struct StreamingValidator {
+    rules: CompiledRules,
+    options: ValidationOptions,
+    states: Vec<RuleState>,
+    coercions: Vec<CoercionState>,
+    /// Rows loaded with cells past the header.
+    overlong: Failures,
+    stats: Vec<(String, ColumnStats)>,
+    headers: Option<Vec<String>>,
+    rows: usize,
+    start: Instant,
+}
+
+impl StreamingValidator {
+    /// Rules with an invalid regex pattern fail with that error; use
+    /// `from_compiled` to reject them up front.
+    pub fn new(rules: &[Rule], options: ValidationOptions) -> Self {
+        Self::from_compiled(&CompiledRules::compile_lenient(rules), options)
+    }
+
+    pub fn from_compiled(rules: &CompiledRules, options: ValidationOptions) -> Self {
+        let states = rules
+            .errors
+            .iter()
+            .map(|error| RuleState {
+                error: error.clone(),
+                ..RuleState::default()
+            })
+            .collect();
+        StreamingValidator {
+            rules: rules.clone(),
+            options,
+            states,
+            coercions: Vec::new(),
+            overlong: Failures::default(),
+            stats: Vec::new(),
+            headers: None,
+            rows: 0,
+            start: Instant::now(),
+        }
+    }
+
+    /// Number of rows seen so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Running statistics of a column over the rows seen so far.
+    pub fn column_stats(&self, name: &str) -> Option<&ColumnStats> {
+        self.stats
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, stats)| stats)
+    }
+
+    /// Evaluates every rule on the next batch of rows. Batches must share the
+    /// same columns.
+    pub fn update(&mut self, batch: &Dataset) {
+        let offset = self.rows;
+        let keep = self.keep();
+        self.headers.get_or_insert_with(|| batch.headers.clone());
+
+        let regexes = &self.rules.regexes;
+        for (index, (rule, state)) in self.rules.rules.iter().zip(&mut self.states).enumerate() {
+            if state.error.is_some() {
+                continue;
+            }
+            if let Err(e) = check::check_rule(index, rule, |name| {
+                batch.get_column_by_name(name).map(Column::dtype)
+            }) {
+                state.error = Some(e.to_string());
+                continue;
+            }
+            let (columns, rows) = match select_rows(batch, rule, regexes) {
+                Ok(selection) => selection,
+                Err(e) => {
+                    state.error = Some(e.to_string());
+                    continue;
+                }
+            };
+            if rule.constraint.is_aggregate() {
+                state
+                    .aggregate
+                    .merge(Aggregate::of(&rule.constraint, &columns[0]));
+                continue;
+            }
+            let columns: Vec<&Column> = columns.iter().map(AsRef::as_ref).collect();
+            let row_of = |i: usize| offset + rows.as_ref().map_or(i, |rows| rows[i]);
+            let key_of = |i: usize| -> RowKey { columns.iter().map(|c| c.key_value(i)).collect() };
+            let render = |i: usize| -> String {
+                let values: Vec<Option<String>> =
+                    columns.iter().map(|c| c.format_value(i)).collect();
+                format_values(&values)
+            };
+
+            if tracks_keys(&rule.constraint) {
+                let len = columns[0].len();
+                for i in 0..len {
+                    let key = key_of(i);
+                    // null keys are left to the null policy, as in `constraint_mask`
+                    if key.contains(&None) {
+                        match rule.null_policy {
+                            NullPolicy::Fail => {
+                                state.failures.push(row_of(i), render(i), keep);
+                                state.evaluated += 1;
+                            }
+                            NullPolicy::Pass => state.evaluated += 1,
+                            NullPolicy::Exclude => {}
+                        }
+                        continue;
+                    }
+                    state.evaluated += 1;
+                    match state.seen.entry(key) {
+                        Entry::Occupied(_) => state.failures.push(row_of(i), render(i), keep),
+                        Entry::Vacant(entry) => {
+                            entry.insert((row_of(i), render(i)));
+                        }
+                    }
+                }
+            } else {
+                let mut mask = evaluate(&columns, &rule.constraint, regexes);
+                if let Some(accept) = ordering(&rule.constraint) {
+                    carry_ordering(state, columns[0], &mut mask, accept, row_of);
+                }
+                let (mask, evaluated) = resolve_nulls(mask, rule.null_policy);
+                for i in mask.not_true_indices() {
+                    state.failures.push(row_of(i), render(i), keep);
+                }
+                state.evaluated += evaluated;
+            }
+        }
+
+        let mut coerced: Vec<&Coercion> = batch.coercions.iter().collect();
+        coerced.sort_by_key(|c| c.row);
+        for coercion in coerced {
+            let idx = match self
+                .coercions
+                .iter()
+                .position(|c| c.column == coercion.column)
+            {
+                Some(idx) => idx,
+                None => {
+                    self.coercions.push(CoercionState {
+                        column: coercion.column.clone(),
+                        expected: coercion.expected.clone(),
+                        failures: Failures::default(),
+                    });
+                    self.coercions.len() - 1
+                }
+            };
+            self.coercions[idx].failures.push(
+                offset + coercion.row,
+                coerced_value(coercion.value.as_deref()),
+                keep,
+            );
+        }
+        for overlong in &batch.overlong_rows {
+            self.overlong
+                .push(offset + overlong.row, overlong_value(&overlong.extra), keep);
+        }
+
+        for (name, column) in batch.headers.iter().zip(&batch.columns) {
+            self.merge_stats(name, &ColumnStats::from_column(column));
+        }
+
+        self.rows += batch.shape().0;
+    }
+
+    /// Folds in a validator that saw the rows directly following this one's,
+    /// e.g. the next batch validated on another thread. Both must have been
+    /// created with the same rules and options.
+    pub fn merge(&mut self, other: StreamingValidator) {
+        debug_assert!(
+            self.rules.rules == other.rules.rules,
+            "merging validators with different rules"
+        );
+        let offset = self.rows;
+        let keep = self.keep();
+
+        let rules = &self.rules.rules;
+        for (rule, (state, other)) in rules.iter().zip(self.states.iter_mut().zip(other.states)) {
+            state.error = state.error.take().or(other.error);
+            state.evaluated += other.evaluated;
+            state.aggregate.merge(other.aggregate);
+            let mut failures = other.failures.shifted(offset);
+            // the first value `other` saw is checked against the last one seen here
+            if let Some(accept) = ordering(&rule.constraint) {
+                match (&state.last, other.first) {
+                    (Some(last), Some((row, value))) => {
+                        if value.compare(last)[0].map(accept) != Some(true) {
+                            failures.count += 1;
+                            failures
+                                .rows
+                                .push((row + offset, format_values(&[value.format_value(0)])));
+                        }
+                    }
+                    (None, first) => {
+                        state.first = first.map(|(row, value)| (row + offset, value));
+                    }
+                    (Some(_), None) => {}
+                }
+                state.last = other.last.or(state.last.take());
+            }
+            // a key first seen in `other` is a duplicate if this side saw it already
+            for (key, (row, sample)) in other.seen {
+                match state.seen.entry(key) {
+                    Entry::Occupied(_) => {
+                        failures.count += 1;
+                        failures.rows.push((row + offset, sample));
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert((row + offset, sample));
+                    }
+                }
+            }
+            state.failures.merge(failures, keep);
+        }
+
+        for other in other.coercions {
+            let failures = other.failures.shifted(offset);
+            match self.coercions.iter_mut().find(|c| c.column == other.column) {
+                Some(state) => state.failures.merge(failures, keep),
+                None => self.coercions.push(CoercionState { failures, ..other }),
+            }
+        }
+        self.overlong.merge(other.overlong.shifted(offset), keep);
+
+        for (name, stats) in &other.stats {
+            self.merge_stats(name, stats);
+        }
+
+        if self.headers.is_none() {
+            self.headers = other.headers;
+        }
+        self.rows += other.rows;
+    }
+
+    /// Builds the report for all rows seen.
+    pub fn finish(self) -> Report {
+        let options = &self.options;
+        let results: Vec<ValidationResult> = self
+            .rules
+            .rules
+            .iter()
+            .zip(&self.states)
+            .map(|(rule, state)| match &state.error {
+                Some(error) => ValidationResult::failed(rule, 0, error),
+                None if rule.constraint.is_aggregate() => aggregate_result(rule, &state.aggregate),
+                None => rule_result(
+                    rule,
+                    state.failures.count,
+                    state.evaluated,
+                    state.failures.failed_rows(options.max_failed_rows),
+                    state.failures.sample_values(options.max_sample_values),
+                ),
+            })
+            .collect();
+
+        let mut findings = Vec::new();
+        let headers = self.headers.unwrap_or_default();
+        for header in &headers {
+            if let Some(state) = self.coercions.iter().find(|c| &c.column == header) {
+                findings.push(type_conformance_result(
+                    header,
+                    &state.expected,
+                    state.failures.count,
+                    self.rows,
+                    state.failures.failed_rows(options.max_failed_rows),
+                    state.failures.sample_values(options.max_sample_values),
+                ));
+            }
+        }
+        if self.overlong.count > 0 {
+            findings.push(row_length_result(
+                self.overlong.count,
+                self.rows,
+                self.overlong.failed_rows(options.max_failed_rows),
+                self.overlong.sample_values(options.max_sample_values),
+            ));
+        }
+
+        Report::new(results, (self.rows, headers.len()), self.start.elapsed())
+            .with_load_findings(findings)
+    }
+
+    fn keep(&self) -> usize {
+        self.options
+            .max_failed_rows
+            .max(self.options.max_sample_values)
+    }
+
+    fn merge_stats(&mut self, name: &str, stats: &ColumnStats) {
+        match self.stats.iter_mut().find(|(column, _)| column == name) {
+            Some((_, existing)) => existing.merge(stats),
+            None => self.stats.push((name.to_string(), stats.clone())),
+        }
+    }
+}
+
+/// Carries an ordering constraint across batches: the batch's first non-null
+/// value is compared with the last one of earlier batches (or remembered for
+/// `merge` when there is none), and the batch's last non-null value is kept.
+fn carry_ordering(
+    state: &mut RuleState,
+    column: &Column,
+    mask: &mut Mask,
+    accept: fn(Ordering) -> bool,
+    row_of: impl Fn(usize) -> usize,
+) {
+    let not_null: Vec<usize> = column
+        .is_null()
+        .iter()
+        .enumerate()
+        .filter(|(_, null)| !**null)
+        .map(|(row, _)| row)
+        .collect();
+    let (Some(&first), Some(&last)) = (not_null.first(), not_null.last()) else {
+        return;
+    };
+    let value = column.slice(first..first + 1);
+    match &state.last {
+        Some(previous) => mask.set(first, value.compare(previous)[0].map(accept)),
+        None => state.first = Some((row_of(first), value)),
+    }
+    state.last = Some(column.slice(last..last + 1));
+}
+
+/// Constraints whose outcome for a row depends on earlier rows.
+fn tracks_keys(constraint: &Constraint) -> bool {
+    matches!(constraint, Constraint::Unique | Constraint::UniqueWith(_))
+}
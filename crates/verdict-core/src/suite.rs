@@ -0,0 +1,200 @@
+//! Rule suites stored as JSON, YAML or TOML files.
+//!
+//! A suite is a table with a `rules` list (JSON and YAML also accept a bare
+//! list). Every format goes through a JSON value tree, so constraints are
+//! written the same way everywhere:
+//!
+//! ```yaml
+//! rules:
+//!   - column: id
+//!     constraint: not_null
+//!   - column: age
+//!     constraint: { between: { min: 0, max: 120 } }
+//!     tolerance: { mostly: 0.99 }
+//!     severity: warning
+//!     null_policy: exclude
+//!   - column: zip
+//!     constraint: { matches_regex: '^\d{5}$' }
+//!     when: { column: country, constraint: { in_set: [US] } }
+//!   - column: price
+//!     constraint: { any: [{ not: not_null }, { between: { min: 0, max: 100 } }] }
+//!   - column: price
+//!     constraint: { mean_between: { min: 10, max: 50 } }
+//! ```
+//!
+//! Schema files use the same formats, with a `fields` list:
+//!
+//! ```yaml
+//! fields:
+//!   - { name: id, dtype: int }
+//!   - { name: name, dtype: str }
+//! ```
+
+use std::path::Path;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{dataset::Schema, rules::Rule};
+
+#[derive(Debug, Error)]
+pub enum SuiteError {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error("Unsupported rule suite format for '{path}' (expected .json, .yaml, .yml or .toml)")]
+    UnsupportedFormat { path: String },
+
+    #[error("Failed to parse {format} file: {message}")]
+    ParseError {
+        format: SuiteFormat,
+        message: String,
+    },
+
+    #[error("Rule suite must be a list of rules or a table with a 'rules' list")]
+    MissingRules,
+
+    #[error("Invalid rule at rules[{index}]{}: {message}", column_label(.column))]
+    InvalidRule {
+        index: usize,
+        column: Option<String>,
+        message: String,
+    },
+
+    #[error("Invalid schema: {message}")]
+    InvalidSchema { message: String },
+
+    #[error("Failed to write {format} rule suite: {message}")]
+    SerializeError {
+        format: SuiteFormat,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiteFormat {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl SuiteFormat {
+    /// Picks the format from the file extension, if that format is enabled.
+    pub fn from_path(path: &Path) -> Option<SuiteFormat> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "json" => Some(SuiteFormat::Json),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(SuiteFormat::Yaml),
+            #[cfg(feature = "toml")]
+            "toml" => Some(SuiteFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SuiteFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuiteFormat::Json => write!(f, "JSON"),
+            #[cfg(feature = "yaml")]
+            SuiteFormat::Yaml => write!(f, "YAML"),
+            #[cfg(feature = "toml")]
+            SuiteFormat::Toml => write!(f, "TOML"),
+        }
+    }
+}
+
+pub fn load_rules(path: impl AsRef<Path>) -> Result<Vec<Rule>, SuiteError> {
+    let path = path.as_ref();
+    let format = format_for(path)?;
+    parse_rules(&std::fs::read_to_string(path)?, format)
+}
+
+pub fn save_rules(path: impl AsRef<Path>, rules: &[Rule]) -> Result<(), SuiteError> {
+    let path = path.as_ref();
+    let format = format_for(path)?;
+    std::fs::write(path, rules_to_string(rules, format)?)?;
+    Ok(())
+}
+
+pub fn parse_rules(input: &str, format: SuiteFormat) -> Result<Vec<Rule>, SuiteError> {
+    let entries = match parse_value(input, format)? {
+        Value::Array(entries) => entries,
+        Value::Object(mut table) => match table.remove("rules") {
+            Some(Value::Array(entries)) => entries,
+            _ => return Err(SuiteError::MissingRules),
+        },
+        _ => return Err(SuiteError::MissingRules),
+    };
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let column = entry
+                .get("column")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            serde_json::from_value(entry).map_err(|e| SuiteError::InvalidRule {
+                index,
+                column,
+                message: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn rules_to_string(rules: &[Rule], format: SuiteFormat) -> Result<String, SuiteError> {
+    let serialize_error = |message: String| SuiteError::SerializeError { format, message };
+    let rules = serde_json::to_value(rules).map_err(|e| serialize_error(e.to_string()))?;
+    let suite = Value::Object([("rules".to_string(), rules)].into_iter().collect());
+    match format {
+        SuiteFormat::Json => {
+            serde_json::to_string_pretty(&suite).map_err(|e| serialize_error(e.to_string()))
+        }
+        #[cfg(feature = "yaml")]
+        SuiteFormat::Yaml => {
+            serde_yaml::to_string(&suite).map_err(|e| serialize_error(e.to_string()))
+        }
+        #[cfg(feature = "toml")]
+        SuiteFormat::Toml => toml::to_string(&suite).map_err(|e| serialize_error(e.to_string())),
+    }
+}
+
+pub fn load_schema(path: impl AsRef<Path>) -> Result<Schema, SuiteError> {
+    let path = path.as_ref();
+    let format = format_for(path)?;
+    parse_schema(&std::fs::read_to_string(path)?, format)
+}
+
+pub fn parse_schema(input: &str, format: SuiteFormat) -> Result<Schema, SuiteError> {
+    serde_json::from_value(parse_value(input, format)?).map_err(|e| SuiteError::InvalidSchema {
+        message: e.to_string(),
+    })
+}
+
+fn parse_value(input: &str, format: SuiteFormat) -> Result<Value, SuiteError> {
+    let parse_error = |message: String| SuiteError::ParseError { format, message };
+    match format {
+        SuiteFormat::Json => serde_json::from_str(input).map_err(|e| parse_error(e.to_string())),
+        #[cfg(feature = "yaml")]
+        SuiteFormat::Yaml => serde_yaml::from_str(input).map_err(|e| parse_error(e.to_string())),
+        #[cfg(feature = "toml")]
+        SuiteFormat::Toml => toml::from_str(input).map_err(|e| parse_error(e.to_string())),
+    }
+}
+
+fn format_for(path: &Path) -> Result<SuiteFormat, SuiteError> {
+    SuiteFormat::from_path(path).ok_or_else(|| SuiteError::UnsupportedFormat {
+        path: path.display().to_string(),
+    })
+}
+
+fn column_label(column: &Option<String>) -> String {
+    match column {
+        Some(column) => format!(" (column '{}')", column),
+        None => String::new(),
+    }
+}
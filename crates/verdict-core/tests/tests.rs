@@ -1,8 +1,20 @@
 #[cfg(test)]
 mod tests {
+    use chrono::{DateTime, NaiveDate};
+    use rust_decimal::Decimal;
     use verdict_core::{
-        dataset::{BoolColumn, Column, Dataset, FloatColumn, InSetValues, IntColumn, StrColumn},
-        rules::{Constraint, Rule, validate},
+        dataset::{
+            Bitmap, BoolColumn, CategoricalColumn, Column, Dataset, DateColumn, DateTimeColumn,
+            DateTimeOps, DecimalColumn, DecimalOps, FloatColumn, InSetValues, IntColumn, Mask,
+            NumericOps, StrColumn, ops::ComparableOps,
+        },
+        errors::ValidationError,
+        report::{ColumnSummary, Report},
+        rules::{
+            CompiledRules, Constraint, NullPolicy, Rule, Severity, StreamingValidator, Tolerance,
+            ValidationOptions, Verdict, check_rules, validate, validate_compiled,
+            validate_with_options,
+        },
     };
 
     fn make_all_types_dataset() -> Dataset {
@@ -14,22 +26,28 @@ mod tests {
                 "active".to_string(),
             ],
             vec![
-                Column::Int(IntColumn(vec![Some(1), Some(2), Some(3), Some(4), Some(5)])),
-                Column::Str(StrColumn(vec![
+                Column::Int(IntColumn::from(vec![
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    Some(4),
+                    Some(5),
+                ])),
+                Column::Str(StrColumn::from(vec![
                     Some("alice".to_string()),
                     Some("bob".to_string()),
                     Some("charlie".to_string()),
                     Some("diana".to_string()),
                     Some("eve".to_string()),
                 ])),
-                Column::Float(FloatColumn(vec![
+                Column::Float(FloatColumn::from(vec![
                     Some(95.5),
                     Some(87.3),
                     Some(92.0),
                     Some(78.9),
                     Some(100.0),
                 ])),
-                Column::Bool(BoolColumn(vec![
+                Column::Bool(BoolColumn::from(vec![
                     Some(true),
                     Some(false),
                     Some(true),
@@ -49,16 +67,28 @@ mod tests {
                 "active".to_string(),
             ],
             vec![
-                Column::Int(IntColumn(vec![None, Some(2), None, Some(4), None])),
-                Column::Str(StrColumn(vec![
+                Column::Int(IntColumn::from(vec![None, Some(2), None, Some(4), None])),
+                Column::Str(StrColumn::from(vec![
                     None,
                     Some("bob".to_string()),
                     Some("charlie".to_string()),
                     None,
                     None,
                 ])),
-                Column::Float(FloatColumn(vec![None, None, Some(3.3), None, Some(5.5)])),
-                Column::Bool(BoolColumn(vec![None, Some(false), None, Some(false), None])),
+                Column::Float(FloatColumn::from(vec![
+                    None,
+                    None,
+                    Some(3.3),
+                    None,
+                    Some(5.5),
+                ])),
+                Column::Bool(BoolColumn::from(vec![
+                    None,
+                    Some(false),
+                    None,
+                    Some(false),
+                    None,
+                ])),
             ],
         )
     }
@@ -172,8 +202,8 @@ mod tests {
 
     #[test]
     fn test_numeric_ops_all_null_returns_none() {
-        let int_col = Column::Int(IntColumn(vec![None, None, None]));
-        let float_col = Column::Float(FloatColumn(vec![None, None, None]));
+        let int_col = Column::Int(IntColumn::from(vec![None, None, None]));
+        let float_col = Column::Float(FloatColumn::from(vec![None, None, None]));
 
         for col in [&int_col, &float_col] {
             assert!(col.sum().is_none());
@@ -187,8 +217,8 @@ mod tests {
 
     #[test]
     fn test_numeric_ops_empty_returns_none() {
-        let int_col = Column::Int(IntColumn(vec![]));
-        let float_col = Column::Float(FloatColumn(vec![]));
+        let int_col = Column::Int(IntColumn::from(vec![]));
+        let float_col = Column::Float(FloatColumn::from(vec![]));
 
         for col in [&int_col, &float_col] {
             assert!(col.sum().is_none());
@@ -202,15 +232,15 @@ mod tests {
 
     #[test]
     fn test_std_single_value_returns_none() {
-        let int_col = Column::Int(IntColumn(vec![Some(42)]));
-        let float_col = Column::Float(FloatColumn(vec![Some(1.5)]));
+        let int_col = Column::Int(IntColumn::from(vec![Some(42)]));
+        let float_col = Column::Float(FloatColumn::from(vec![Some(1.5)]));
         assert!(int_col.std().is_none());
         assert!(float_col.std().is_none());
     }
 
     #[test]
     fn test_comparable_ops_all_null() {
-        let col = Column::Int(IntColumn(vec![None, None, None]));
+        let col = Column::Int(IntColumn::from(vec![None, None, None]));
         assert_eq!(col.gt(1.0), vec![None, None, None]);
         assert_eq!(col.ge(1.0), vec![None, None, None]);
         assert_eq!(col.lt(1.0), vec![None, None, None]);
@@ -221,14 +251,99 @@ mod tests {
 
     #[test]
     fn test_string_ops_all_null() {
-        let col = Column::Str(StrColumn(vec![None, None]));
+        let col = Column::Str(StrColumn::from(vec![None, None]));
         assert_eq!(col.contains("a"), vec![None, None]);
         assert_eq!(col.starts_with("a"), vec![None, None]);
         assert_eq!(col.ends_with("a"), vec![None, None]);
-        assert_eq!(col.matches_regex(".*"), vec![None, None]);
+        assert_eq!(col.matches_regex(".*").unwrap(), vec![None, None]);
         assert_eq!(col.str_length(), vec![None, None]);
     }
 
+    // ── Column storage ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_bitmap() {
+        let bits: Bitmap = (0..150).map(|i| i % 3 == 0).collect();
+        assert_eq!(bits.len(), 150);
+        assert_eq!(bits.count_ones(), 50);
+        assert!(bits.get(63) && !bits.get(64) && bits.get(147));
+
+        // word-aligned and unaligned slices both drop the bits past the end
+        let aligned = bits.slice(64..100);
+        assert!(aligned.iter().eq((64..100).map(|i| i % 3 == 0)));
+        assert_eq!(aligned.count_ones(), 12);
+        let unaligned = bits.slice(1..130);
+        assert!(unaligned.iter().eq((1..130).map(|i| i % 3 == 0)));
+
+        let taken = bits.take(&[149, 0, 66, 1]);
+        assert!(taken.iter().eq([false, true, true, false]));
+        assert_eq!(Bitmap::filled(70, true).count_ones(), 70);
+        assert!(Bitmap::filled(70, true).all_set());
+    }
+
+    #[test]
+    fn test_column_buffers() {
+        let values: Vec<Option<i64>> = (0..100).map(|i| (i % 7 != 0).then_some(i)).collect();
+        let ints = IntColumn::from(values.clone());
+        assert_eq!(ints.values().len(), 100);
+        assert_eq!(ints.values()[7], 0); // null slots hold the default
+        assert_eq!(ints.not_null_count(), 85);
+        assert!(ints.iter().eq(values.iter().copied()));
+        assert!(ints.slice(60..90).iter().eq(values[60..90].iter().copied()));
+
+        let strs: StrColumn = [Some("ab"), None, Some(""), Some("héllo"), None, Some("z")]
+            .into_iter()
+            .collect();
+        assert_eq!(strs.data(), "abhélloz");
+        assert_eq!(strs.offsets(), [0, 2, 2, 2, 8, 8, 9]);
+        assert_eq!(strs.get(2), Some(""));
+        assert_eq!(strs.get(4), None);
+        let slice = strs.slice(2..6);
+        assert_eq!(slice.offsets(), [0, 0, 6, 6, 7]);
+        assert!(slice.iter().eq([Some(""), Some("héllo"), None, Some("z")]));
+        let taken = strs.take(&[5, 1, 0]);
+        assert!(taken.iter().eq([Some("z"), None, Some("ab")]));
+
+        let bools = BoolColumn::from(vec![Some(true), None, Some(false)]);
+        assert!(bools.slice(1..3).iter().eq([None, Some(false)]));
+        assert_eq!(bools.not_null_count(), 2);
+    }
+
+    #[test]
+    fn test_mask_three_valued_logic() {
+        let t = Some(true);
+        let f = Some(false);
+        // every pairing of true, false and null
+        let a = Mask::from(vec![t, t, t, f, f, f, None, None, None]);
+        let b = Mask::from(vec![t, f, None, t, f, None, t, f, None]);
+        assert_eq!(a.and(&b), vec![t, f, None, f, f, f, None, f, None]);
+        assert_eq!(a.or(&b), vec![t, t, t, t, f, None, t, None, None]);
+        assert_eq!(a.not(), vec![f, f, f, t, t, t, None, None, None]);
+        assert_eq!((a.count_true(), a.count_false(), a.count_null()), (3, 3, 3));
+
+        let ints = Column::Int(IntColumn::from(vec![Some(1), None, Some(3), Some(4)]));
+        let mask = ints.gt(1.0).and(&ints.lt(4.0));
+        assert_eq!(mask, vec![f, None, t, f]);
+        assert!(mask.true_indices().eq([2]));
+        assert!(mask.not_true_indices().eq([0, 1, 3]));
+    }
+
+    #[test]
+    fn test_mask_indices_across_words() {
+        let mut mask: Mask = (0..130).map(|i| Some(i % 64 == 0)).collect();
+        mask.set(5, None);
+        assert!(mask.true_indices().eq([0, 64, 128]));
+        assert_eq!(mask.not_true_indices().count(), 127);
+        assert_eq!(mask.count_null(), 1);
+
+        // appending at an unaligned length keeps every row in place
+        let mut joined = Mask::from(vec![Some(false); 3]);
+        joined.extend(&mask);
+        assert_eq!(joined.len(), 133);
+        assert!(joined.true_indices().eq([3, 67, 131]));
+        assert_eq!(joined.get(8), None);
+    }
+
     #[test]
     fn test_numeric_ops_single_value_std() {
         let dataset = make_with_nulls_dataset();
@@ -346,9 +461,10 @@ mod tests {
             vec![Some(true), Some(false), Some(true), Some(false), Some(true)]
         );
         assert_eq!(
-            name_col.matches_regex("^[a-c]"),
+            name_col.matches_regex("^[a-c]").unwrap(),
             vec![Some(true), Some(true), Some(true), Some(false), Some(false)]
         );
+        assert!(name_col.matches_regex("[a-c").is_err());
     }
 
     #[test]
@@ -524,208 +640,2928 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_contains() {
+    fn test_validate_invalid_regex_fails_rule() {
         let dataset = make_all_types_dataset();
         let results = validate(
             &dataset,
-            &[Rule::new("name", Constraint::Contains("li".to_string()))],
+            &[
+                Rule::new("name", Constraint::MatchesRegex("^[a-z".to_string())),
+                Rule::new("id", Constraint::NotNull),
+            ],
         );
         assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 3);
-
-        // alice and charlie contain "li" — pass case with 2 matches
-        let results = validate(
-            &dataset,
-            &[Rule::new("name", Constraint::Contains("b".to_string()))],
+        assert!(
+            results[0]
+                .error
+                .as_deref()
+                .unwrap()
+                .starts_with("Invalid regex '^[a-z' in rules[0] (column 'name'): ")
         );
-        assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 4); // only bob contains "b"
+        assert!(results[1].passed);
     }
 
     #[test]
-    fn test_validate_starts_with() {
-        let dataset = make_all_types_dataset();
-        let results = validate(
-            &dataset,
-            &[Rule::new("name", Constraint::StartsWith("a".to_string()))],
-        );
-        assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 4);
-    }
+    fn test_compiled_rules() {
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("score", Constraint::GreaterThan(80.0))
+                .when("name", Constraint::MatchesRegex("(".to_string())),
+        ];
+        match CompiledRules::new(&rules).unwrap_err() {
+            ValidationError::InvalidRegex {
+                index,
+                column,
+                pattern,
+                ..
+            } => {
+                assert_eq!(index, 1);
+                assert_eq!(column, "score");
+                assert_eq!(pattern, "(");
+            }
+            e => panic!("unexpected error: {}", e),
+        }
 
-    #[test]
-    fn test_validate_ends_with() {
+        let compiled = CompiledRules::new(&[Rule::new(
+            "name",
+            Constraint::MatchesRegex("^[a-d]".to_string()),
+        )])
+        .unwrap();
+        assert_eq!(compiled.rules().len(), 1);
         let dataset = make_all_types_dataset();
-        let results = validate(
-            &dataset,
-            &[Rule::new("name", Constraint::EndsWith("e".to_string()))],
-        );
-        assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 2); // bob, diana don't end with "e"
+        for _ in 0..2 {
+            let report = validate_compiled(&dataset, &compiled, &ValidationOptions::default());
+            assert_eq!(report[0].failed_rows, vec![4]);
+        }
     }
 
     #[test]
-    fn test_validate_length_between() {
+    fn test_check_rules() {
         let dataset = make_all_types_dataset();
-        // names: alice(5), bob(3), charlie(7), diana(5), eve(3)
-        let results = validate(
-            &dataset,
-            &[Rule::new(
-                "name",
-                Constraint::LengthBetween { min: 3, max: 7 },
-            )],
-        );
-        assert!(results[0].passed);
+        let schema = dataset.schema();
+        let names: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, dataset.headers);
 
-        let results = validate(
-            &dataset,
-            &[Rule::new(
-                "name",
-                Constraint::LengthBetween { min: 4, max: 6 },
-            )],
+        let rules = vec![
+            Rule::new("id", Constraint::GreaterThan(0.0)),
+            Rule::new("name", Constraint::GreaterThan(0.0)),
+            Rule::new("score", Constraint::InSet(InSetValues::IntSet(vec![1, 2]))),
+            Rule::new("score", Constraint::LessThanColumn("id".to_string())),
+            Rule::new("id", Constraint::LessThanColumn("name".to_string())),
+            Rule::new("name", Constraint::NotNull)
+                .when("active", Constraint::StartsWith("t".to_string())),
+            Rule::new("missing", Constraint::NotNull),
+            Rule::new("id", Constraint::NotNull)
+                .when("id", Constraint::GreaterThanColumn("score".to_string())),
+        ];
+        let errors: Vec<String> = check_rules(&schema, &rules)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Incompatible type in rules[1] (column 'name'): > 0 requires an Int, Float or \
+                 Decimal column, found 'name' (Str)",
+                "Incompatible type in rules[2] (column 'score'): in {1, 2} requires an Int column, \
+                 found 'score' (Float)",
+                "Incompatible type in rules[4] (column 'id'): < column 'name' requires columns of \
+                 comparable types (both numeric, both text or the same type), found 'id' (Int) and \
+                 'name' (Str)",
+                "Incompatible type in rules[5] (column 'name'): starts with 't' requires a Str or \
+                 Categorical column, found 'active' (Bool)",
+                "Column 'missing' not found in dataset",
+                "Misplaced constraint in rules[7] (column 'id'): > column 'score' compares rows or \
+                 columns and can't be used as a condition",
+            ]
         );
-        assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 3); // bob(3), charlie(7), eve(3)
     }
 
     #[test]
-    fn test_validate_in_set() {
+    fn test_validate_incompatible_type() {
         let dataset = make_all_types_dataset();
         let results = validate(
             &dataset,
-            &[Rule::new(
-                "name",
-                Constraint::InSet(InSetValues::StrSet(vec![
-                    "alice".to_string(),
-                    "bob".to_string(),
-                    "charlie".to_string(),
-                    "diana".to_string(),
-                    "eve".to_string(),
-                ])),
-            )],
-        );
-        assert!(results[0].passed);
-
-        let results = validate(
-            &dataset,
-            &[Rule::new(
-                "name",
-                Constraint::InSet(InSetValues::StrSet(vec![
-                    "alice".to_string(),
-                    "bob".to_string(),
-                ])),
-            )],
+            &[
+                Rule::new("name", Constraint::GreaterThan(0.0)),
+                Rule::new("id", Constraint::GreaterThan(0.0)),
+            ],
         );
         assert!(!results[0].passed);
-        assert_eq!(results[0].failed_count, 3);
+        assert_eq!(results[0].failed_count, 0);
+        assert_eq!(results[0].evaluated_count, 0);
+        assert!(
+            results[0]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("requires an Int, Float or Decimal column, found 'name' (Str)")
+        );
+        assert!(results[1].passed);
     }
 
-    #[test]
-    fn test_validate_column_not_found() {
-        let dataset = make_all_types_dataset();
-        let results = validate(&dataset, &[Rule::new("nonexistent", Constraint::NotNull)]);
-        assert!(!results[0].passed);
-        assert!(results[0].error.is_some());
+    fn make_sku_dataset() -> Dataset {
+        Dataset::new(
+            vec!["sku".to_string(), "price".to_string()],
+            vec![
+                Column::Str(
+                    [
+                        Some("SKU-0001"),
+                        Some("SKU-12"),
+                        None,
+                        Some("ABC-00001"),
+                        Some("test"),
+                        Some("SKU-12345678"),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                Column::Float(FloatColumn::from(vec![
+                    Some(5.0),
+                    None,
+                    Some(150.0),
+                    Some(-1.0),
+                    Some(100.0),
+                    Some(0.0),
+                ])),
+            ],
+        )
     }
 
     #[test]
-    fn test_validate_with_nulls() {
-        let dataset = make_with_nulls_dataset();
-        // id column has nulls in rows 0, 2, 4
-        let results = validate(&dataset, &[Rule::new("id", Constraint::GreaterThan(0.0))]);
-        assert!(!results[0].passed);
-        // nulls count as failures
-        assert!(results[0].failed_count > 0);
+    fn test_validate_combinators() {
+        let dataset = make_sku_dataset();
+        let sku_format = Constraint::All(vec![
+            Constraint::StartsWith("SKU-".to_string()),
+            Constraint::LengthBetween { min: 8, max: 12 },
+        ]);
+        let rules = vec![
+            Rule::new(
+                "price",
+                Constraint::Any(vec![
+                    Constraint::Not(Box::new(Constraint::NotNull)),
+                    Constraint::Between {
+                        min: 0.0,
+                        max: 100.0,
+                    },
+                ]),
+            ),
+            Rule::new("sku", sku_format.clone()),
+            Rule::new(
+                "sku",
+                Constraint::Not(Box::new(Constraint::InSet(InSetValues::StrSet(vec![
+                    "test".to_string(),
+                    "dummy".to_string(),
+                ])))),
+            ),
+            Rule::new(
+                "sku",
+                Constraint::Any(vec![
+                    sku_format,
+                    Constraint::Not(Box::new(Constraint::MatchesRegex("^[A-Z]".to_string()))),
+                ]),
+            ),
+            Rule::new("sku", Constraint::All(vec![])),
+        ];
+        let results = validate(&dataset, &rules);
+        let failed: Vec<&[usize]> = results.iter().map(|r| &r.failed_rows[..]).collect();
+        // a null value is null under Not, and only All/Any with a decided
+        // branch turns it into a pass or fail
+        assert_eq!(
+            failed,
+            vec![&[2, 3][..], &[1, 2, 3, 4], &[2, 4], &[1, 2, 3], &[]]
+        );
+        let constraints: Vec<&str> = results.iter().map(|r| r.constraint.as_str()).collect();
+        assert_eq!(
+            constraints,
+            vec![
+                "null or between 0 and 100",
+                "starts with 'SKU-' and length between 8 and 12",
+                "not in {'test', 'dummy'}",
+                "(starts with 'SKU-' and length between 8 and 12) or not matches regex '^[A-Z]'",
+                "true",
+            ]
+        );
+        assert_eq!(
+            results[0].error.as_deref(),
+            Some("values not satisfying: null or between 0 and 100")
+        );
     }
 
     #[test]
-    fn test_validate_multiple_rules() {
-        let dataset = make_all_types_dataset();
+    fn test_check_nested_constraints() {
+        let schema = make_sku_dataset().schema();
         let rules = vec![
-            Rule::new("id", Constraint::NotNull),
-            Rule::new("id", Constraint::GreaterThan(0.0)),
-            Rule::new("name", Constraint::NotNull),
             Rule::new(
-                "score",
-                Constraint::Between {
-                    min: 0.0,
-                    max: 100.0,
-                },
+                "sku",
+                Constraint::Any(vec![Constraint::NotNull, Constraint::Unique]),
+            ),
+            Rule::new(
+                "price",
+                Constraint::Not(Box::new(Constraint::All(vec![
+                    Constraint::GreaterThan(0.0),
+                    Constraint::StartsWith("x".to_string()),
+                ]))),
+            ),
+            Rule::new(
+                "price",
+                Constraint::All(vec![Constraint::LessThanColumn("missing".to_string())]),
             ),
+            Rule::new("sku", Constraint::Not(Box::new(Constraint::IsWeekday)))
+                .when("price", Constraint::Not(Box::new(Constraint::NotNull))),
         ];
-        let results = validate(&dataset, &rules);
-        assert_eq!(results.len(), 4);
-        assert!(results[0].passed);
-        assert!(results[1].passed);
-        assert!(results[2].passed);
-        assert!(results[3].passed);
+        let errors: Vec<String> = check_rules(&schema, &rules)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Misplaced constraint in rules[0] (column 'sku'): unique compares rows or columns \
+                 and can't be used inside All, Any or Not",
+                "Incompatible type in rules[1] (column 'price'): starts with 'x' requires a Str or \
+                 Categorical column, found 'price' (Float)",
+                "Misplaced constraint in rules[2] (column 'price'): < column 'missing' compares \
+                 rows or columns and can't be used inside All, Any or Not",
+                "Incompatible type in rules[3] (column 'sku'): weekday requires a Date or DateTime \
+                 column, found 'sku' (Str)",
+            ]
+        );
     }
-}
-
-#[cfg(feature = "csv")]
-mod csv_tests {
-    use verdict_core::{
-        csv_loader::DatasetCsvExt,
-        dataset::{DataType, Dataset, Field, Schema},
-    };
 
-    fn make_schema() -> Schema {
-        Schema::new(vec![
-            Field::new("id", DataType::Int),
-            Field::new("name", DataType::Str),
-            Field::new("score", DataType::Float),
-            Field::new("active", DataType::Bool),
-        ])
+    fn make_country_columns() -> (Column, Column) {
+        let values = [
+            Some("DE"),
+            Some("FR"),
+            None,
+            Some("DE"),
+            Some("US"),
+            Some("FR"),
+        ];
+        (
+            Column::Str(values.iter().copied().collect()),
+            Column::Categorical(CategoricalColumn::from_values(values)),
+        )
     }
 
     #[test]
-    fn test_load_csv() {
-        let schema = make_schema();
-        let dataset = Dataset::from_csv("tests/fixtures/all_types.csv", &schema).unwrap();
-        assert_eq!(dataset.headers, vec!["id", "name", "score", "active"]);
-        assert_eq!(dataset.shape(), (5, 4));
-    }
+    fn test_categorical_column() {
+        let (plain, encoded) = make_country_columns();
+        let Column::Categorical(col) = &encoded else {
+            unreachable!()
+        };
+        assert_eq!(&col.dictionary[..], ["DE", "FR", "US"]);
+        assert_eq!(
+            col.codes,
+            vec![Some(0), Some(1), None, Some(0), Some(2), Some(1)]
+        );
+        assert_eq!(col.get(4), Some("US"));
+        let Column::Str(strings) = &plain else {
+            unreachable!()
+        };
+        assert!(col.to_str_column().iter().eq(strings.iter()));
+
+        // every string operation agrees with the plain column
+        assert_eq!(encoded.unique_count(), plain.unique_count());
+        assert_eq!(encoded.duplicated(), plain.duplicated());
+        assert_eq!(encoded.contains("E"), plain.contains("E"));
+        assert_eq!(encoded.starts_with("F"), plain.starts_with("F"));
+        assert_eq!(encoded.ends_with("S"), plain.ends_with("S"));
+        assert_eq!(
+            encoded.matches_regex("^[DU]").unwrap(),
+            plain.matches_regex("^[DU]").unwrap()
+        );
+        assert_eq!(encoded.str_length(), plain.str_length());
+        assert_eq!(encoded.equal_str("FR"), plain.equal_str("FR"));
+        let set = InSetValues::StrSet(vec!["DE".to_string(), "US".to_string()]);
+        assert_eq!(encoded.is_in(&set), plain.is_in(&set));
+        assert_eq!(
+            col.between("E", "G"),
+            vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(false),
+                Some(true)
+            ]
+        );
+        assert_eq!(
+            encoded.compare(&plain),
+            vec![
+                Some(std::cmp::Ordering::Equal),
+                Some(std::cmp::Ordering::Equal),
+                None,
+                Some(std::cmp::Ordering::Equal),
+                Some(std::cmp::Ordering::Equal),
+                Some(std::cmp::Ordering::Equal),
+            ]
+        );
 
-    #[test]
-    fn test_load_csv_with_nulls() {
-        let schema = make_schema();
-        let dataset = Dataset::from_csv("tests/fixtures/with_nulls.csv", &schema).unwrap();
-        assert_eq!(dataset.headers, vec!["id", "name", "score", "active"]);
-        assert_eq!(dataset.shape(), (5, 4));
+        // slices share the dictionary
+        let Column::Categorical(tail) = encoded.slice(3..6) else {
+            unreachable!()
+        };
+        assert!(std::sync::Arc::ptr_eq(&tail.dictionary, &col.dictionary));
+        assert_eq!(encoded.take(&[5, 2]).format_value(0).as_deref(), Some("FR"));
     }
 
     #[test]
-    fn test_load_csv_invalid_path() {
-        let schema = Schema::new(vec![Field::new("id", DataType::Int)]);
-        let result = Dataset::from_csv("nonexistent.csv", &schema);
-        assert!(result.is_err());
+    fn test_validate_categorical() {
+        let (plain, encoded) = make_country_columns();
+        let rules = vec![
+            Rule::new("country", Constraint::NotNull),
+            Rule::new("country", Constraint::Unique),
+            Rule::new(
+                "country",
+                Constraint::InSet(InSetValues::StrSet(vec![
+                    "DE".to_string(),
+                    "FR".to_string(),
+                ])),
+            ),
+            Rule::new("country", Constraint::LengthBetween { min: 2, max: 2 }),
+            Rule::new("country", Constraint::EqualColumn("plain".to_string())),
+        ];
+        let headers = vec!["country".to_string(), "plain".to_string()];
+        let expected = validate(
+            &Dataset::new(headers.clone(), vec![plain.clone(), plain.clone()]),
+            &rules,
+        );
+        let results = validate(&Dataset::new(headers, vec![encoded, plain]), &rules);
+        for (result, expected) in results.iter().zip(&expected) {
+            assert_eq!(result.to_string(), expected.to_string());
+            assert_eq!(result.failed_rows, expected.failed_rows);
+        }
+        assert_eq!(results[2].failed_rows, vec![2, 4]);
     }
 
-    #[test]
-    fn test_parse_bool_values() {
-        let schema = make_schema();
-        let dataset = Dataset::from_csv("tests/fixtures/all_types.csv", &schema).unwrap();
-        let col = dataset.get_column_by_name("active").unwrap();
-        assert_eq!(col.len(), 5);
-        assert_eq!(col.null_count(), 0);
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
     }
 
-    #[test]
-    fn test_parse_bool_invalid() {
-        let schema = Schema::new(vec![Field::new("name", DataType::Bool)]);
-        let result = Dataset::from_csv("tests/fixtures/all_types.csv", &schema);
-        assert!(result.is_err());
+    fn make_decimal_dataset() -> Dataset {
+        Dataset::new(
+            vec!["amount".to_string(), "float".to_string(), "qty".to_string()],
+            vec![
+                Column::Decimal(DecimalColumn(vec![
+                    Some(dec("0.10")),
+                    Some(dec("0.20")),
+                    Some(dec("0.30")),
+                    None,
+                    Some(dec("1.505")),
+                ])),
+                Column::Float(FloatColumn::from(vec![
+                    Some(0.1),
+                    Some(0.2),
+                    Some(0.1 + 0.2),
+                    Some(0.4),
+                    Some(1.5),
+                ])),
+                Column::Int(IntColumn::from(vec![
+                    Some(1),
+                    Some(20),
+                    Some(300),
+                    None,
+                    Some(5),
+                ])),
+            ],
+        )
     }
 
     #[test]
-    fn test_load_csv_parse_error() {
-        let schema = Schema::new(vec![
-            Field::new("id", DataType::Int),
-            Field::new("name", DataType::Int),
-            Field::new("score", DataType::Float),
-            Field::new("active", DataType::Bool),
+    fn test_decimal_ops() {
+        let dataset = make_decimal_dataset();
+        let Some(Column::Decimal(amount)) = dataset.get_column_by_name("amount") else {
+            panic!("amount should be a Decimal column");
+        };
+        assert_eq!(amount.sum(), Some(dec("2.105")));
+        assert_eq!(amount.min(), Some(dec("0.1")));
+        assert_eq!(amount.max(), Some(dec("1.505")));
+        assert_eq!(amount.median(), Some(0.25));
+        assert_eq!(
+            amount.scale(),
+            vec![Some(1), Some(1), Some(1), None, Some(3)]
+        );
+        assert_eq!(
+            amount.fits_precision(3, 2),
+            vec![Some(true), Some(true), Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            amount.equal(dec("0.3")),
+            vec![Some(false), Some(false), Some(true), None, Some(false)]
+        );
+
+        // f64 bounds are taken as written, unlike Float columns
+        let column = dataset.get_column_by_name("amount").unwrap();
+        assert_eq!(column.sum(), Some(2.105));
+        assert_eq!(
+            column.equal(0.3),
+            vec![Some(false), Some(false), Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            dataset.get_column_by_name("float").unwrap().equal(0.3),
+            vec![Some(false); 5]
+        );
+        assert_eq!(
+            column.gt(1.505),
+            vec![Some(false), Some(false), Some(false), None, Some(false)]
+        );
+
+        let qty = dataset.get_column_by_name("qty").unwrap();
+        assert_eq!(
+            qty.fits_precision(3, 2),
+            vec![Some(true), Some(false), Some(false), None, Some(true)]
+        );
+
+        let repeated = Column::Decimal(DecimalColumn(vec![
+            Some(dec("1.5")),
+            Some(dec("1.50")),
+            Some(dec("1.5")),
+        ]));
+        assert_eq!(repeated.duplicated(), vec![false, true, true]);
+        assert_eq!(repeated.unique_count(), 1);
+        assert_eq!(repeated.key_value(0), repeated.key_value(1));
+        assert_eq!(repeated.format_value(1).as_deref(), Some("1.50"));
+    }
+
+    #[test]
+    fn test_validate_decimal_keys_ignore_scale() {
+        let dataset = Dataset::new(
+            vec!["amount".to_string(), "site".to_string()],
+            vec![
+                Column::Decimal(DecimalColumn(vec![
+                    Some(dec("1.50")),
+                    Some(dec("1.5")),
+                    Some(dec("2.00")),
+                    None,
+                    Some(dec("0.10")),
+                    Some(dec("0.20")),
+                    Some(dec("0.30")),
+                ])),
+                Column::Str(StrColumn::from(vec![Some("a".to_string()); 7])),
+            ],
+        );
+        let rules = vec![
+            Rule::new("amount", Constraint::Unique),
+            Rule::new("amount", Constraint::UniqueWith(vec!["site".to_string()])),
+            Rule::new(
+                "amount",
+                Constraint::DistinctCountBetween { min: 5, max: 5 },
+            ),
+        ];
+        // row 1 repeats 1.50; row 3 is null
+        let results = validate(&dataset, &rules);
+        assert_eq!(results[0].failed_rows, vec![1, 3]);
+        assert_eq!(results[1].failed_rows, vec![1, 3]);
+        assert!(results[2].passed);
+        assert_eq!(results[2].observed, Some(5.0));
+
+        let mut validator = StreamingValidator::new(&rules, ValidationOptions::default());
+        validator.update(&dataset);
+        let streamed = validator.finish();
+        for (a, b) in streamed.results.iter().zip(&results) {
+            assert_eq!(a.to_string(), b.to_string());
+        }
+    }
+
+    #[test]
+    fn test_validate_decimal() {
+        let dataset = make_decimal_dataset();
+        let rules = vec![
+            Rule::new("amount", Constraint::Between { min: 0.1, max: 0.3 }),
+            Rule::new("amount", Constraint::Equal(0.3)),
+            Rule::new("amount", Constraint::MaxScale(2)),
+            Rule::new(
+                "qty",
+                Constraint::Precision {
+                    precision: 3,
+                    scale: 2,
+                },
+            ),
+            Rule::new(
+                "amount",
+                Constraint::GreaterThanOrEqualColumn("float".to_string()),
+            ),
+            Rule::new(
+                "amount",
+                Constraint::LessThanOrEqualColumn("qty".to_string()),
+            ),
+            Rule::new(
+                "amount",
+                Constraint::InSet(InSetValues::FloatSet(vec![0.1, 0.2])),
+            ),
+        ];
+        let results = validate(&dataset, &rules);
+        let failed: Vec<Vec<usize>> = results.iter().map(|r| r.failed_rows.clone()).collect();
+        assert_eq!(
+            failed,
+            vec![
+                vec![3, 4],
+                vec![0, 1, 3, 4],
+                vec![3, 4],
+                vec![1, 2, 3],
+                // 0.30 < 0.1 + 0.2 in f64
+                vec![2, 3],
+                vec![3],
+                vec![2, 3, 4],
+            ]
+        );
+        assert_eq!(
+            results[2].to_string(),
+            "FAIL: column 'amount' — at most 2 decimal places — 2 of 5 values failed: \
+             values with more than 2 decimal places (rows: 3, 4) (sample: null, 1.505)"
+        );
+
+        // Decimal sums are exact; the slack a Float sum gets would pass 2.1050000001
+        let results = validate(
+            &dataset,
+            &[
+                Rule::new("amount", Constraint::SumEqual(2.105)),
+                Rule::new("amount", Constraint::SumEqual(2.1050000001)),
+            ],
+        );
+        assert!(results[0].passed);
+        assert!(!results[1].passed);
+        assert_eq!(results[1].observed, Some(2.105));
+
+        let errors: Vec<String> = check_rules(
+            &dataset.schema(),
+            &[
+                Rule::new("float", Constraint::MaxScale(2)),
+                Rule::new("amount", Constraint::GreaterThan(0.0)),
+            ],
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Incompatible type in rules[0] (column 'float'): at most 2 decimal places requires \
+                 an Int or Decimal column, found 'float' (Float)"
+            ]
+        );
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn make_dates_dataset() -> Dataset {
+        // 2024-01-06 is a Saturday
+        let days = ["2024-01-05", "2024-01-06", "2024-01-08", "2024-01-08"];
+        Dataset::new(
+            vec!["day".to_string(), "at".to_string()],
+            vec![
+                Column::Date(DateColumn(
+                    days.iter().map(|d| Some(date(d))).chain([None]).collect(),
+                )),
+                Column::DateTime(DateTimeColumn(
+                    [
+                        Some("2024-01-05T23:30:00-02:00"),
+                        Some("2024-01-06T12:00:00Z"),
+                        None,
+                        Some("2024-01-08T00:00:00+01:00"),
+                        Some("2024-01-09T00:00:00Z"),
+                    ]
+                    .into_iter()
+                    .map(|t| t.map(|t| DateTime::parse_from_rfc3339(t).unwrap()))
+                    .collect(),
+                )),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_date_ops() {
+        let dataset = make_dates_dataset();
+        let Some(Column::Date(day)) = dataset.get_column_by_name("day") else {
+            panic!("day should be a Date column");
+        };
+        assert_eq!(day.month(), vec![Some(1), Some(1), Some(1), Some(1), None]);
+        assert_eq!(
+            day.is_weekend(),
+            vec![Some(false), Some(true), Some(false), Some(false), None]
+        );
+        assert_eq!(
+            day.gt(date("2024-01-06")),
+            vec![Some(false), Some(false), Some(true), Some(true), None]
+        );
+
+        // DateTime values use the date in their own offset
+        let Some(Column::DateTime(at)) = dataset.get_column_by_name("at") else {
+            panic!("at should be a DateTime column");
+        };
+        assert_eq!(at.day(), vec![Some(5), Some(6), None, Some(8), Some(9)]);
+        assert_eq!(
+            at.year(),
+            vec![Some(2024), Some(2024), None, Some(2024), Some(2024)]
+        );
+        assert_eq!(
+            at.between_dates(date("2024-01-06"), date("2024-01-08")),
+            vec![Some(false), Some(true), None, Some(true), Some(false)]
+        );
+        let noon = DateTime::parse_from_rfc3339("2024-01-06T12:00:00Z").unwrap();
+        assert_eq!(
+            at.ge(noon),
+            vec![Some(false), Some(true), None, Some(true), Some(true)]
+        );
+        assert_eq!(
+            at.equal(DateTime::parse_from_rfc3339("2024-01-06T14:00:00+02:00").unwrap()),
+            vec![Some(false), Some(true), None, Some(false), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_validate_date_constraints() {
+        let dataset = make_dates_dataset();
+        let reference = DateTime::parse_from_rfc3339("2024-01-09T00:00:00Z").unwrap();
+        let rules = vec![
+            Rule::new(
+                "day",
+                Constraint::DateBetween {
+                    min: date("2024-01-06"),
+                    max: date("2024-01-31"),
+                },
+            ),
+            Rule::new("at", Constraint::NotInFuture),
+            Rule::new(
+                "at",
+                Constraint::Freshness {
+                    max_age_secs: 2 * 24 * 3600,
+                    reference: Some(reference),
+                },
+            ),
+            Rule::new("day", Constraint::IsWeekday),
+            Rule::new("at", Constraint::IsWeekend),
+            Rule::new(
+                "day",
+                Constraint::Freshness {
+                    max_age_secs: 24 * 3600,
+                    reference: Some(reference),
+                },
+            ),
+        ];
+        let results = validate(&dataset, &rules);
+        let failed: Vec<Vec<usize>> = results.iter().map(|r| r.failed_rows.clone()).collect();
+        assert_eq!(
+            failed,
+            vec![
+                vec![0, 4],
+                vec![2],
+                vec![0, 1, 2],
+                vec![1, 4],
+                vec![0, 2, 3, 4],
+                vec![0, 1, 4],
+            ]
+        );
+        assert_eq!(
+            results[0].to_string(),
+            "FAIL: column 'day' — date between 2024-01-06 and 2024-01-31 — \
+             2 of 5 values failed: dates not between 2024-01-06 and 2024-01-31 \
+             (rows: 0, 4) (sample: 2024-01-05, null)"
+        );
+
+        let far_future = Dataset::new(
+            vec!["day".to_string()],
+            vec![Column::Date(DateColumn(vec![
+                Some(date("2000-01-01")),
+                Some(date("9999-12-31")),
+            ]))],
+        );
+        let results = validate(&far_future, &[Rule::new("day", Constraint::NotInFuture)]);
+        assert_eq!(results[0].failed_rows, vec![1]);
+    }
+
+    #[test]
+    fn test_validate_increasing() {
+        let dataset = Dataset::new(
+            vec!["seq".to_string(), "day".to_string()],
+            vec![
+                Column::Int(IntColumn::from(vec![
+                    Some(1),
+                    Some(2),
+                    Some(2),
+                    None,
+                    Some(1),
+                    Some(3),
+                ])),
+                Column::Date(DateColumn(vec![
+                    Some(date("2024-03-01")),
+                    Some(date("2024-02-01")),
+                    Some(date("2024-02-01")),
+                    Some(date("2024-01-15")),
+                    None,
+                    Some(date("2024-01-01")),
+                ])),
+            ],
+        );
+        let rules = vec![
+            Rule::new("seq", Constraint::Increasing { strict: false }),
+            Rule::new("seq", Constraint::Increasing { strict: true }),
+            Rule::new("day", Constraint::Decreasing { strict: false }),
+            Rule::new("day", Constraint::Decreasing { strict: true }),
+        ];
+        let results = validate(&dataset, &rules);
+        let failed: Vec<Vec<usize>> = results.iter().map(|r| r.failed_rows.clone()).collect();
+        // nulls fail and are skipped as the previous value
+        assert_eq!(failed, vec![vec![3, 4], vec![2, 3, 4], vec![4], vec![2, 4]]);
+        assert!(
+            results[1]
+                .to_string()
+                .contains("values not strictly increasing")
+        );
+
+        let errors: Vec<String> = check_rules(
+            &dataset.schema(),
+            &[
+                Rule::new("seq", Constraint::IsWeekend),
+                Rule::new("day", Constraint::Increasing { strict: true }),
+            ],
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Incompatible type in rules[0] (column 'seq'): weekend requires a Date or DateTime \
+                 column, found 'seq' (Int)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_contains() {
+        let dataset = make_all_types_dataset();
+        let results = validate(
+            &dataset,
+            &[Rule::new("name", Constraint::Contains("li".to_string()))],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 3);
+
+        // alice and charlie contain "li" — pass case with 2 matches
+        let results = validate(
+            &dataset,
+            &[Rule::new("name", Constraint::Contains("b".to_string()))],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 4); // only bob contains "b"
+    }
+
+    #[test]
+    fn test_validate_starts_with() {
+        let dataset = make_all_types_dataset();
+        let results = validate(
+            &dataset,
+            &[Rule::new("name", Constraint::StartsWith("a".to_string()))],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 4);
+    }
+
+    #[test]
+    fn test_validate_ends_with() {
+        let dataset = make_all_types_dataset();
+        let results = validate(
+            &dataset,
+            &[Rule::new("name", Constraint::EndsWith("e".to_string()))],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 2); // bob, diana don't end with "e"
+    }
+
+    #[test]
+    fn test_validate_length_between() {
+        let dataset = make_all_types_dataset();
+        // names: alice(5), bob(3), charlie(7), diana(5), eve(3)
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "name",
+                Constraint::LengthBetween { min: 3, max: 7 },
+            )],
+        );
+        assert!(results[0].passed);
+
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "name",
+                Constraint::LengthBetween { min: 4, max: 6 },
+            )],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 3); // bob(3), charlie(7), eve(3)
+    }
+
+    #[test]
+    fn test_validate_in_set() {
+        let dataset = make_all_types_dataset();
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "name",
+                Constraint::InSet(InSetValues::StrSet(vec![
+                    "alice".to_string(),
+                    "bob".to_string(),
+                    "charlie".to_string(),
+                    "diana".to_string(),
+                    "eve".to_string(),
+                ])),
+            )],
+        );
+        assert!(results[0].passed);
+
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "name",
+                Constraint::InSet(InSetValues::StrSet(vec![
+                    "alice".to_string(),
+                    "bob".to_string(),
+                ])),
+            )],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].failed_count, 3);
+    }
+
+    #[test]
+    fn test_validate_column_not_found() {
+        let dataset = make_all_types_dataset();
+        let results = validate(&dataset, &[Rule::new("nonexistent", Constraint::NotNull)]);
+        assert!(!results[0].passed);
+        assert!(results[0].error.is_some());
+    }
+
+    #[test]
+    fn test_validate_with_nulls() {
+        let dataset = make_with_nulls_dataset();
+        // id column has nulls in rows 0, 2, 4
+        let results = validate(&dataset, &[Rule::new("id", Constraint::GreaterThan(0.0))]);
+        assert!(!results[0].passed);
+        // nulls count as failures
+        assert!(results[0].failed_count > 0);
+    }
+
+    #[test]
+    fn test_validate_reports_failed_rows_and_samples() {
+        let dataset = make_with_nulls_dataset();
+        // id = [None, 2, None, 4, None]
+        let results = validate(&dataset, &[Rule::new("id", Constraint::GreaterThan(3.0))]);
+        assert_eq!(results[0].failed_count, 4);
+        assert_eq!(results[0].failed_rows, vec![0, 1, 2, 4]);
+        assert_eq!(results[0].sample_values, vec!["null", "2", "null", "null"]);
+    }
+
+    #[test]
+    fn test_validate_failed_rows_are_capped() {
+        let dataset = make_all_types_dataset();
+        let options = ValidationOptions {
+            max_failed_rows: 2,
+            max_sample_values: 1,
+        };
+        let results = validate_with_options(
+            &dataset,
+            &[Rule::new("id", Constraint::GreaterThan(4.0))],
+            &options,
+        );
+        assert_eq!(results[0].failed_count, 4);
+        assert_eq!(results[0].failed_rows, vec![0, 1]);
+        assert_eq!(results[0].sample_values, vec!["1"]);
+    }
+
+    #[test]
+    fn test_validate_unique_reports_repeated_rows() {
+        let dataset = make_all_types_dataset();
+        // active = [true, false, true, false, true]
+        let results = validate(&dataset, &[Rule::new("active", Constraint::Unique)]);
+        assert_eq!(results[0].failed_count, 3);
+        assert_eq!(results[0].failed_rows, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_validate_passed_has_no_failed_rows() {
+        let dataset = make_all_types_dataset();
+        let results = validate(&dataset, &[Rule::new("id", Constraint::NotNull)]);
+        assert!(results[0].failed_rows.is_empty());
+        assert!(results[0].sample_values.is_empty());
+    }
+
+    #[test]
+    fn test_validate_column_comparison() {
+        let dataset = make_all_types_dataset();
+        // score = [95.5, 87.3, 92.0, 78.9, 100.0], id = [1, 2, 3, 4, 5]
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "score",
+                Constraint::GreaterThanColumn("id".to_string()),
+            )],
+        );
+        assert!(results[0].passed);
+        assert_eq!(results[0].column, "score, id");
+
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "id",
+                Constraint::GreaterThanOrEqualColumn("score".to_string()),
+            )],
+        );
+        assert_eq!(results[0].failed_count, 5);
+        assert_eq!(results[0].sample_values[0], "(1, 95.5)");
+    }
+
+    #[test]
+    fn test_validate_column_comparison_with_nulls() {
+        let dataset = make_with_nulls_dataset();
+        // id = [None, 2, None, 4, None], score = [None, None, 3.3, None, 5.5]
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "id",
+                Constraint::LessThanColumn("score".to_string()),
+            )],
+        );
+        assert_eq!(results[0].failed_count, 5);
+    }
+
+    #[test]
+    fn test_validate_column_comparison_missing_column() {
+        let dataset = make_all_types_dataset();
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "id",
+                Constraint::EqualColumn("nonexistent".to_string()),
+            )],
+        );
+        assert!(!results[0].passed);
+        assert!(results[0].error.as_deref().unwrap().contains("nonexistent"));
+    }
+
+    #[test]
+    fn test_validate_unique_with() {
+        let dataset = make_all_types_dataset();
+        // active alone repeats, but (active, id) is unique
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "active",
+                Constraint::UniqueWith(vec!["id".to_string()]),
+            )],
+        );
+        assert!(results[0].passed);
+
+        let dataset = make_with_nulls_dataset();
+        // (id, active) = (None, None), (2, false), (None, None), (4, false), (None, None);
+        // keys with a null are left to the null policy
+        let rule = Rule::new("id", Constraint::UniqueWith(vec!["active".to_string()]));
+        let results = validate(&dataset, std::slice::from_ref(&rule));
+        assert_eq!(results[0].failed_rows, vec![0, 2, 4]);
+        let results = validate(&dataset, &[rule.null_policy(NullPolicy::Exclude)]);
+        assert!(results[0].passed);
+        assert_eq!(results[0].evaluated_count, 2);
+    }
+
+    #[test]
+    fn test_validate_aggregates() {
+        let dataset = make_all_types_dataset();
+        // id = [1, 2, 3, 4, 5], score = [95.5, 87.3, 92.0, 78.9, 100.0]
+        let rules = vec![
+            Rule::new("id", Constraint::MeanBetween { min: 1.0, max: 3.0 }),
+            Rule::new("id", Constraint::SumEqual(15.0)),
+            Rule::new("score", Constraint::StdBelow(1.0)),
+            Rule::new("id", Constraint::MedianBetween { min: 4.0, max: 5.0 }),
+            Rule::new("id", Constraint::MinBetween { min: 1.0, max: 1.0 }),
+            Rule::new("id", Constraint::MaxBetween { min: 0.0, max: 4.0 }),
+            Rule::new("name", Constraint::DistinctCountBetween { min: 5, max: 5 }),
+            Rule::new("id", Constraint::RowCountBetween { min: 1, max: 3 })
+                .when("id", Constraint::GreaterThan(2.0)),
+        ];
+        let results = validate(&dataset, &rules);
+        let passed: Vec<bool> = results.iter().map(|r| r.passed).collect();
+        assert_eq!(
+            passed,
+            vec![true, true, false, false, true, false, true, true]
+        );
+        assert!(results[2].observed.unwrap() > 8.0);
+        assert_eq!(
+            results[0].to_string(),
+            "PASS: column 'id' — mean between 1 and 3 (observed 3)"
+        );
+        assert_eq!(
+            results[3].to_string(),
+            "FAIL: column 'id' — median between 4 and 5 — observed 3: median not between 4 and 5"
+        );
+        assert_eq!(
+            (results[3].failed_count, results[3].evaluated_count),
+            (1, 1)
+        );
+        assert_eq!(results[7].observed, Some(3.0));
+
+        // id = [None, 2, None, 4, None], score = [None, None, 3.3, None, 5.5]
+        let dataset = make_with_nulls_dataset();
+        let undefined = Rule::new(
+            "id",
+            Constraint::MeanBetween {
+                min: 0.0,
+                max: 10.0,
+            },
+        )
+        .when("score", Constraint::GreaterThan(4.0));
+        let rules = vec![
+            Rule::new("id", Constraint::NullRatioAtMost(0.5)),
+            undefined.clone(),
+            undefined.null_policy(NullPolicy::Pass),
+        ];
+        let results = validate(&dataset, &rules);
+        assert_eq!(results[0].observed, Some(0.6));
+        assert_eq!(results[0].error.as_deref(), Some("null ratio above 0.5"));
+        assert!(!results[1].passed);
+        assert_eq!(results[1].observed, None);
+        assert_eq!(
+            results[1].error.as_deref(),
+            Some("mean is undefined without non-null values")
+        );
+        assert!(results[2].passed);
+
+        let errors: Vec<String> = check_rules(
+            &dataset.schema(),
+            &[
+                Rule::new("name", Constraint::StdBelow(1.0)),
+                Rule::new("id", Constraint::NotNull).when("score", Constraint::StdBelow(1.0)),
+                Rule::new(
+                    "id",
+                    Constraint::Not(Box::new(Constraint::RowCountBetween { min: 0, max: 1 })),
+                ),
+                Rule::new("id", Constraint::MeanBetween { min: 0.0, max: 1.0 }).max_failures(1),
+            ],
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Incompatible type in rules[0] (column 'name'): std < 1 requires an Int, Float or \
+                 Decimal column, found 'name' (Str)",
+                "Misplaced constraint in rules[1] (column 'id'): std < 1 checks a whole column and \
+                 can't be used as a condition",
+                "Misplaced constraint in rules[2] (column 'id'): row count between 0 and 1 checks \
+                 a whole column and can't be used inside All, Any or Not",
+                "Misplaced constraint in rules[3] (column 'id'): mean between 0 and 1 checks a \
+                 whole column and can't be used with a tolerance",
+            ]
+        );
+        // a tolerance would turn a failed statistic into a pass
+        let results = validate(
+            &dataset,
+            &[Rule::new("id", Constraint::MeanBetween { min: 0.0, max: 1.0 }).mostly(0.0)],
+        );
+        assert!(!results[0].passed);
+        assert_eq!(results[0].observed, None);
+    }
+
+    #[test]
+    fn test_validate_null_policy() {
+        let dataset = make_with_nulls_dataset();
+        // id = [None, 2, None, 4, None], score = [None, None, 3.3, None, 5.5]
+        let rules = |null_policy| {
+            vec![
+                Rule::new("id", Constraint::GreaterThan(3.0)).null_policy(null_policy),
+                Rule::new("score", Constraint::Unique).null_policy(null_policy),
+                Rule::new("id", Constraint::LessThanColumn("score".to_string()))
+                    .null_policy(null_policy),
+                Rule::new("id", Constraint::NotNull).null_policy(null_policy),
+                Rule::new("score", Constraint::Increasing { strict: true })
+                    .null_policy(null_policy)
+                    .mostly(0.5),
+            ]
+        };
+        let summary = |report: &Report| -> Vec<(Vec<usize>, usize, bool)> {
+            report
+                .iter()
+                .map(|r| (r.failed_rows.clone(), r.evaluated_count, r.passed))
+                .collect()
+        };
+
+        assert_eq!(
+            summary(&validate(&dataset, &rules(NullPolicy::Fail))),
+            vec![
+                (vec![0, 1, 2, 4], 5, false),
+                (vec![0, 1, 3], 5, false),
+                (vec![0, 1, 2, 3, 4], 5, false),
+                (vec![0, 2, 4], 5, false),
+                (vec![0, 1, 3], 5, false),
+            ]
+        );
+        assert_eq!(
+            summary(&validate(&dataset, &rules(NullPolicy::Pass))),
+            vec![
+                (vec![1], 5, false),
+                (vec![], 5, true),
+                (vec![], 5, true),
+                // NotNull decides every row itself
+                (vec![0, 2, 4], 5, false),
+                (vec![], 5, true),
+            ]
+        );
+        assert_eq!(
+            summary(&validate(&dataset, &rules(NullPolicy::Exclude))),
+            vec![
+                (vec![1], 2, false),
+                (vec![], 2, true),
+                (vec![], 0, true),
+                (vec![0, 2, 4], 5, false),
+                (vec![], 2, true),
+            ]
+        );
+        assert_eq!(NullPolicy::parse("Exclude"), Some(NullPolicy::Exclude));
+        assert_eq!(NullPolicy::default().to_string(), "fail");
+    }
+
+    #[test]
+    fn test_validate_exactly_one_not_null() {
+        let dataset = make_with_nulls_dataset();
+        // id = [None, 2, None, 4, None], score = [None, None, 3.3, None, 5.5]
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "id",
+                Constraint::ExactlyOneNotNull(vec!["score".to_string()]),
+            )],
+        );
+        assert_eq!(results[0].failed_rows, vec![0]); // both null
+
+        let results = validate(
+            &dataset,
+            &[Rule::new(
+                "id",
+                Constraint::ExactlyOneNotNull(vec!["name".to_string()]),
+            )],
+        );
+        // name = [None, "bob", "charlie", None, None]
+        assert_eq!(results[0].failed_rows, vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn test_validate_conditional_rule() {
+        let dataset = make_all_types_dataset();
+        // only rows 0 (alice) and 2 (charlie) contain "li"
+        let rule = Rule::new("id", Constraint::LessThan(2.0))
+            .when("name", Constraint::Contains("li".to_string()));
+        let results = validate(&dataset, &[rule]);
+        assert_eq!(results[0].evaluated_count, 2);
+        assert_eq!(results[0].failed_count, 1);
+        assert_eq!(results[0].failed_rows, vec![2]);
+        assert_eq!(results[0].sample_values, vec!["3"]);
+    }
+
+    #[test]
+    fn test_validate_conditional_rule_no_matching_rows() {
+        let dataset = make_all_types_dataset();
+        let rule = Rule::new("id", Constraint::LessThan(0.0))
+            .when("score", Constraint::GreaterThan(1000.0));
+        let results = validate(&dataset, &[rule]);
+        assert!(results[0].passed);
+        assert_eq!(results[0].evaluated_count, 0);
+    }
+
+    #[test]
+    fn test_validate_conditional_unique_only_sees_selected_rows() {
+        let dataset = make_all_types_dataset();
+        // active = [true, false, true, false, true]; rows with id >= 4 are
+        // false, true — unique among themselves
+        let rule =
+            Rule::new("active", Constraint::Unique).when("id", Constraint::GreaterThanOrEqual(4.0));
+        let results = validate(&dataset, &[rule]);
+        assert!(results[0].passed);
+        assert_eq!(results[0].evaluated_count, 2);
+    }
+
+    #[test]
+    fn test_validate_conditional_rule_missing_condition_column() {
+        let dataset = make_all_types_dataset();
+        let rule = Rule::new("id", Constraint::NotNull).when("nonexistent", Constraint::NotNull);
+        let results = validate(&dataset, &[rule]);
+        assert!(!results[0].passed);
+        assert!(results[0].error.as_deref().unwrap().contains("nonexistent"));
+    }
+
+    #[test]
+    fn test_validate_conditional_rule_rejects_cross_column_condition() {
+        let dataset = make_all_types_dataset();
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull)
+                .when("id", Constraint::GreaterThanColumn("score".to_string())),
+            Rule::new("id", Constraint::NotNull)
+                .when("name", Constraint::UniqueWith(vec!["active".to_string()])),
+        ];
+        let results = validate(&dataset, &rules);
+        assert!(results.iter().all(|r| !r.passed));
+        assert_eq!(
+            results[0].error.as_deref(),
+            Some(
+                "Misplaced constraint in rules[0] (column 'id'): > column 'score' compares rows or \
+                 columns and can't be used as a condition"
+            )
+        );
+        assert!(
+            results[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .ends_with("can't be used as a condition")
+        );
+    }
+
+    #[test]
+    fn test_validate_mostly_tolerance() {
+        let dataset = make_all_types_dataset();
+        // 1 of 5 ids (20%) is not greater than 1
+        let rule = Rule::new("id", Constraint::GreaterThan(1.0)).mostly(0.8);
+        let results = validate(&dataset, &[rule]);
+        assert!(results[0].passed);
+        assert_eq!(results[0].failed_count, 1);
+        assert_eq!(results[0].failed_ratio, 0.2);
+        assert_eq!(results[0].failed_rows, vec![0]);
+        assert!(results[0].error.is_none());
+
+        let rule = Rule::new("id", Constraint::GreaterThan(1.0)).mostly(0.9);
+        let results = validate(&dataset, &[rule]);
+        assert!(!results[0].passed);
+        assert_eq!(results[0].tolerance, Some(Tolerance::Mostly(0.9)));
+
+        // the builder rejects this; a rule assembled by hand is caught before it runs
+        let mut rule = Rule::new("id", Constraint::GreaterThan(1.0));
+        rule.tolerance = Some(Tolerance::Mostly(1.5));
+        let results = validate(&dataset, std::slice::from_ref(&rule));
+        assert_eq!(
+            results[0].error.as_deref(),
+            Some(
+                "Invalid tolerance in rules[0] (column 'id'): mostly must be between 0.0 and 1.0, got 1.5"
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "mostly must be between 0.0 and 1.0, got 99 for the rule on column 'id'"
+    )]
+    fn test_mostly_out_of_range_panics() {
+        let _ = Rule::new("id", Constraint::NotNull).mostly(99.0);
+    }
+
+    #[test]
+    fn test_validate_max_failures_tolerance() {
+        let dataset = make_all_types_dataset();
+        // 3 ids are not greater than 3
+        let rule = Rule::new("id", Constraint::GreaterThan(3.0)).max_failures(3);
+        assert!(validate(&dataset, &[rule])[0].passed);
+
+        let rule = Rule::new("id", Constraint::GreaterThan(3.0)).max_failures(2);
+        assert!(!validate(&dataset, &[rule])[0].passed);
+    }
+
+    #[test]
+    fn test_validate_tolerance_does_not_hide_errors() {
+        let dataset = make_all_types_dataset();
+        let rule = Rule::new("nonexistent", Constraint::NotNull).mostly(0.0);
+        let results = validate(&dataset, &[rule]);
+        assert!(!results[0].passed);
+        assert_eq!(
+            results[0].to_string(),
+            "FAIL: column 'nonexistent' — not null — Column 'nonexistent' not found in dataset"
+        );
+    }
+
+    #[test]
+    fn test_validate_severity_carried_to_result() {
+        let dataset = make_with_nulls_dataset();
+        let rule = Rule::new("id", Constraint::NotNull).severity(Severity::Warning);
+        let results = validate(&dataset, &[rule]);
+        assert!(!results[0].passed);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert!(results[0].to_string().starts_with("WARN:"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        let dataset = make_with_nulls_dataset();
+        let passing = Rule::new("score", Constraint::GreaterThan(0.0)).mostly(0.0);
+        let info = Rule::new("id", Constraint::NotNull).severity(Severity::Info);
+        let warning = Rule::new("name", Constraint::NotNull).severity(Severity::Warning);
+        let error = Rule::new("active", Constraint::NotNull);
+
+        let verdict = |rules: &[Rule]| Verdict::from_results(&validate(&dataset, rules));
+        assert_eq!(verdict(std::slice::from_ref(&passing)), Verdict::Passed);
+        assert_eq!(verdict(&[passing.clone(), info.clone()]), Verdict::Passed);
+        assert_eq!(verdict(&[info.clone(), warning.clone()]), Verdict::Warning);
+        assert_eq!(verdict(&[warning, error, info]), Verdict::Failed);
+    }
+
+    #[test]
+    fn test_severity_parse() {
+        assert_eq!(Severity::parse("Warning"), Some(Severity::Warning));
+        assert_eq!(Severity::parse("error"), Some(Severity::Error));
+        assert_eq!(Severity::parse("fatal"), None);
+        assert_eq!(Severity::default(), Severity::Error);
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let dataset = make_with_nulls_dataset();
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("id", Constraint::LessThan(10.0)).mostly(0.0),
+            Rule::new("name", Constraint::NotNull).severity(Severity::Warning),
+            Rule::new("score", Constraint::NotNull).severity(Severity::Info),
+        ];
+        let report = validate(&dataset, &rules);
+        assert_eq!(report.shape, (5, 4));
+        assert_eq!(report.len(), 4);
+        assert!(!report.all_passed());
+        assert_eq!(report.passed_count(), 1);
+        assert_eq!(report.failed_count(), 1);
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(report.info_count(), 1);
+        assert_eq!(report.failed().count(), 3);
+        assert_eq!(report.verdict(), Verdict::Failed);
+        assert_eq!(
+            report.column_summaries()[0],
+            ColumnSummary {
+                column: "id".to_string(),
+                rules: 2,
+                passed: 1,
+                failed: 1,
+            }
+        );
+        assert_eq!(report.column_summaries().len(), 3);
+    }
+
+    #[test]
+    fn test_report_display() {
+        let dataset = make_with_nulls_dataset();
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("name", Constraint::NotNull).severity(Severity::Warning),
+        ];
+        let rendered = validate(&dataset, &rules).to_string();
+        assert!(rendered.contains("5 rows × 4 columns, 2 rules"));
+        assert!(rendered.contains("Verdict: FAILED (0 passed, 1 failed, 1 warnings, 0 info)"));
+        assert!(rendered.contains("STATUS  COLUMN  CONSTRAINT  FAILED"));
+        assert!(rendered.contains("WARN    name    not null    3/5"));
+    }
+
+    #[test]
+    fn test_report_all_passed() {
+        let dataset = make_all_types_dataset();
+        let report = validate(&dataset, &[Rule::new("id", Constraint::NotNull)]);
+        assert!(report.all_passed());
+        assert_eq!(report.failed().count(), 0);
+        assert_eq!(report.verdict(), Verdict::Passed);
+    }
+
+    #[test]
+    fn test_validate_multiple_rules() {
+        let dataset = make_all_types_dataset();
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("id", Constraint::GreaterThan(0.0)),
+            Rule::new("name", Constraint::NotNull),
+            Rule::new(
+                "score",
+                Constraint::Between {
+                    min: 0.0,
+                    max: 100.0,
+                },
+            ),
+        ];
+        let results = validate(&dataset, &rules);
+        assert_eq!(results.len(), 4);
+        assert!(results[0].passed);
+        assert!(results[1].passed);
+        assert!(results[2].passed);
+        assert!(results[3].passed);
+    }
+}
+
+#[cfg(feature = "csv")]
+mod csv_tests {
+    use verdict_core::{
+        csv_loader::{
+            CsvBatches, CsvLoadingError, CsvOptions, DatasetCsvExt, InferOptions, infer_schema,
+            validate_csv,
+        },
+        dataset::{
+            Coercion, Column, ColumnStats, DataType, Dataset, ExtraColumns, Field, InSetValues,
+            OverlongRow, Schema,
+        },
+        report::Report,
+        rules::{
+            Constraint, NullPolicy, Rule, Severity, StreamingValidator, ValidationOptions,
+            validate, validate_with_options,
+        },
+    };
+
+    fn make_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("name", DataType::Str),
+            Field::new("score", DataType::Float),
+            Field::new("active", DataType::Bool),
+        ])
+    }
+
+    #[test]
+    fn test_load_csv() {
+        let schema = make_schema();
+        let dataset = Dataset::from_csv("tests/fixtures/all_types.csv", &schema).unwrap();
+        assert_eq!(dataset.headers, vec!["id", "name", "score", "active"]);
+        assert_eq!(dataset.shape(), (5, 4));
+    }
+
+    #[test]
+    fn test_load_csv_with_nulls() {
+        let schema = make_schema();
+        let dataset = Dataset::from_csv("tests/fixtures/with_nulls.csv", &schema).unwrap();
+        assert_eq!(dataset.headers, vec!["id", "name", "score", "active"]);
+        assert_eq!(dataset.shape(), (5, 4));
+    }
+
+    #[test]
+    fn test_load_csv_invalid_path() {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int)]);
+        let result = Dataset::from_csv("nonexistent.csv", &schema);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_bool_values() {
+        let schema = make_schema();
+        let dataset = Dataset::from_csv("tests/fixtures/all_types.csv", &schema).unwrap();
+        let col = dataset.get_column_by_name("active").unwrap();
+        assert_eq!(col.len(), 5);
+        assert_eq!(col.null_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_bool_invalid() {
+        let schema = Schema::new(vec![Field::new("name", DataType::Bool)]);
+        let result = Dataset::from_csv("tests/fixtures/all_types.csv", &schema);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_csv_parse_error() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("name", DataType::Int),
+            Field::new("score", DataType::Float),
+            Field::new("active", DataType::Bool),
+        ]);
+        let result = Dataset::from_csv("tests/fixtures/all_types.csv", &schema);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_csv_matches_columns_by_name() {
+        let dataset = Dataset::from_csv("tests/fixtures/reordered.csv", &make_schema()).unwrap();
+        assert_eq!(dataset.headers, vec!["id", "name", "score", "active"]);
+        assert_eq!(dataset.shape(), (2, 4));
+        match dataset.get_column_by_name("id") {
+            Some(Column::Int(col)) => assert!(col.iter().eq([Some(1), Some(2)])),
+            _ => panic!("id should load as Int"),
+        }
+        match dataset.get_column_by_name("name") {
+            Some(Column::Str(col)) => assert_eq!(col.get(0), Some("alice")),
+            _ => panic!("name should load as Str"),
+        }
+    }
+
+    #[test]
+    fn test_load_csv_keeps_extra_columns() {
+        let schema = make_schema().extra_columns(ExtraColumns::KeepAsStr);
+        let dataset = Dataset::from_csv("tests/fixtures/reordered.csv", &schema).unwrap();
+        assert_eq!(
+            dataset.headers,
+            vec!["id", "name", "score", "active", "notes"]
+        );
+        match dataset.get_column_by_name("notes") {
+            Some(Column::Str(col)) => assert!(col.iter().eq([Some("first"), None])),
+            _ => panic!("notes should load as Str"),
+        }
+    }
+
+    #[test]
+    fn test_load_csv_missing_columns() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("email", DataType::Str),
+            Field::new("country", DataType::Str),
+        ]);
+        let err = Dataset::from_csv("tests/fixtures/all_types.csv", &schema)
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "Missing required columns: email, country");
+        match err {
+            CsvLoadingError::MissingColumns { columns } => {
+                assert_eq!(columns, vec!["email", "country"])
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_csv_duplicate_header() {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int)]);
+        let err = Dataset::from_csv("tests/fixtures/duplicate_header.csv", &schema)
+            .err()
+            .unwrap();
+        assert!(matches!(err, CsvLoadingError::DuplicateColumn { name } if name == "id"));
+        // the duplicate only matters when the schema asks for it
+        let schema = Schema::new(vec![Field::new("name", DataType::Str)]);
+        assert!(Dataset::from_csv("tests/fixtures/duplicate_header.csv", &schema).is_ok());
+    }
+
+    #[test]
+    fn test_load_csv_ordered() {
+        let schema = make_schema().ordered(true);
+        assert!(Dataset::from_csv("tests/fixtures/all_types.csv", &schema).is_ok());
+
+        let err = Dataset::from_csv("tests/fixtures/reordered.csv", &schema)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Expected column 'id' at position 0, found 'active'"
+        );
+
+        let mut fields = make_schema().fields;
+        fields.push(Field::new("notes", DataType::Str));
+        let schema = Schema::new(fields).ordered(true);
+        let err = Dataset::from_csv("tests/fixtures/all_types.csv", &schema)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            CsvLoadingError::ColumnOrderMismatch {
+                position: 4,
+                found: None,
+                ..
+            }
+        ));
+    }
+
+    fn vendor_options() -> CsvOptions {
+        CsvOptions {
+            delimiter: b';',
+            comment: Some(b'#'),
+            trim: true,
+            null_values: vec![String::new(), "NA".to_string()],
+            column_null_values: [("score".to_string(), vec!["-".to_string(), "NA".to_string()])]
+                .into(),
+            true_values: vec!["Y".to_string()],
+            false_values: vec!["N".to_string()],
+            ..CsvOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_load_csv_with_options() {
+        let schema = make_schema().ordered(true);
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/vendor.csv", &schema, &vendor_options())
+                .unwrap();
+        assert_eq!(dataset.shape(), (3, 4));
+        match dataset.get_column_by_name("name") {
+            Some(Column::Str(col)) => {
+                assert!(col.iter().eq([Some("alice"), None, Some("o;neil")]))
+            }
+            _ => panic!("name should load as Str"),
+        }
+        match dataset.get_column_by_name("active") {
+            Some(Column::Bool(col)) => {
+                assert!(col.iter().eq([Some(true), Some(false), Some(true)]))
+            }
+            _ => panic!("active should load as Bool"),
+        }
+    }
+
+    #[test]
+    fn test_load_csv_column_null_values() {
+        let dataset = Dataset::from_csv_with_options(
+            "tests/fixtures/vendor.csv",
+            &make_schema(),
+            &vendor_options(),
+        )
+        .unwrap();
+        assert_eq!(dataset.get_column_by_name("score").unwrap().null_count(), 2);
+
+        // per-column values replace the global ones, so "NA" is a value in score again
+        let mut options = vendor_options();
+        options
+            .column_null_values
+            .insert("score".to_string(), vec!["-".to_string()]);
+        let err =
+            Dataset::from_csv_with_options("tests/fixtures/vendor.csv", &make_schema(), &options)
+                .err()
+                .unwrap();
+        assert!(matches!(
+            err,
+            CsvLoadingError::ParseError { ref column, row: 2, ref value, .. }
+                if column == "score" && value == "NA"
+        ));
+    }
+
+    #[test]
+    fn test_load_csv_bool_values_are_configurable() {
+        let mut options = vendor_options();
+        options.true_values = CsvOptions::default().true_values;
+        options.false_values = CsvOptions::default().false_values;
+        let err =
+            Dataset::from_csv_with_options("tests/fixtures/vendor.csv", &make_schema(), &options)
+                .err()
+                .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse column 'active' row 0: 'Y' is not a valid Bool"
+        );
+    }
+
+    #[test]
+    fn test_load_csv_without_headers() {
+        let options = CsvOptions {
+            delimiter: b'\t',
+            has_headers: false,
+            null_values: vec!["\\N".to_string()],
+            ..CsvOptions::default()
+        };
+        let schema = Schema::new(vec![Field::new("id", DataType::Int)])
+            .extra_columns(ExtraColumns::KeepAsStr);
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/no_headers.tsv", &schema, &options)
+                .unwrap();
+        assert_eq!(dataset.headers, vec!["id", "column_2"]);
+        assert_eq!(dataset.shape(), (3, 2));
+        assert_eq!(
+            dataset.get_column_by_name("column_2").unwrap().null_count(),
+            1
+        );
+
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("code", DataType::Str),
+            Field::new("note", DataType::Str),
+        ]);
+        let err =
+            Dataset::from_csv_with_options("tests/fixtures/no_headers.tsv", &schema, &options)
+                .err()
+                .unwrap();
+        assert!(matches!(err, CsvLoadingError::MissingColumns { columns } if columns == ["note"]));
+    }
+
+    #[test]
+    fn test_load_csv_escape() {
+        let options = CsvOptions {
+            escape: Some(b'\\'),
+            ..CsvOptions::default()
+        };
+        let schema = Schema::new(vec![Field::new("text", DataType::Str)]);
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/escaped.csv", &schema, &options)
+                .unwrap();
+        match dataset.get_column_by_name("text") {
+            Some(Column::Str(col)) => assert_eq!(col.get(0), Some("say \"hi\"")),
+            _ => panic!("text should load as Str"),
+        }
+    }
+
+    #[test]
+    fn test_infer_schema_with_options() {
+        let options = InferOptions {
+            csv: vendor_options(),
+            ..InferOptions::default()
+        };
+        let inference = infer_schema("tests/fixtures/vendor.csv", &options).unwrap();
+        let dtypes: Vec<DataType> = inference.fields.iter().map(|f| f.dtype.clone()).collect();
+        assert_eq!(
+            dtypes,
+            vec![
+                DataType::Int,
+                DataType::Str,
+                DataType::Float,
+                DataType::Bool
+            ]
+        );
+        assert_eq!(inference.fields[1].null_count, 1);
+        assert_eq!(inference.fields[2].null_count, 2);
+    }
+
+    fn dirty_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("score", DataType::Float),
+            Field::new("active", DataType::Bool),
+        ])
+    }
+
+    #[test]
+    fn test_load_csv_lenient() {
+        assert!(Dataset::from_csv("tests/fixtures/dirty.csv", &dirty_schema()).is_err());
+
+        let options = CsvOptions {
+            lenient: true,
+            ..CsvOptions::default()
+        };
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/dirty.csv", &dirty_schema(), &options)
+                .unwrap();
+        assert_eq!(dataset.shape(), (4, 3));
+        assert_eq!(dataset.get_column_by_name("id").unwrap().null_count(), 1);
+        assert_eq!(
+            dataset.coercions,
+            vec![
+                Coercion {
+                    row: 1,
+                    column: "id".to_string(),
+                    value: Some("two".to_string()),
+                    expected: DataType::Int,
+                },
+                Coercion {
+                    row: 2,
+                    column: "score".to_string(),
+                    value: Some("n/a".to_string()),
+                    expected: DataType::Float,
+                },
+                Coercion {
+                    row: 1,
+                    column: "active".to_string(),
+                    value: Some("maybe".to_string()),
+                    expected: DataType::Bool,
+                },
+                Coercion {
+                    row: 2,
+                    column: "active".to_string(),
+                    value: None,
+                    expected: DataType::Bool,
+                },
+            ]
+        );
+        assert_eq!(
+            dataset.overlong_rows,
+            vec![OverlongRow {
+                row: 3,
+                extra: vec!["extra".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_coercions() {
+        let options = CsvOptions {
+            lenient: true,
+            ..CsvOptions::default()
+        };
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/dirty.csv", &dirty_schema(), &options)
+                .unwrap();
+        let report = validate(&dataset, &[Rule::new("id", Constraint::NotNull)]);
+        assert_eq!(report.len(), 5);
+        assert_eq!(report.rule_count, 1);
+        assert_eq!(report.load_findings().len(), 4);
+        assert!(
+            report
+                .to_string()
+                .starts_with("Validation report: 4 rows × 3 columns, 1 rule, 4 load findings in")
+        );
+        assert_eq!(report[0].failed_rows, vec![1]);
+
+        let conformance = &report[3];
+        assert_eq!(conformance.column, "active");
+        assert_eq!(conformance.constraint, "parses as Bool");
+        assert_eq!(conformance.failed_rows, vec![1, 2]);
+        assert_eq!(conformance.sample_values, vec!["maybe", "<missing>"]);
+        assert_eq!(
+            conformance.to_string(),
+            "FAIL: column 'active' — parses as Bool — 2 of 4 values failed: \
+             values could not be parsed as Bool (rows: 1, 2) (sample: maybe, <missing>)"
+        );
+        assert_eq!(
+            report[4].to_string(),
+            "FAIL: column '*' — row length = header length — 1 of 4 values failed: rows had \
+             more cells than the header; the extra cells were dropped (rows: 3) (sample: extra)"
+        );
+        assert_eq!(report.failed_count(), 5);
+    }
+
+    #[test]
+    fn test_infer_schema() {
+        let inference =
+            infer_schema("tests/fixtures/all_types.csv", &InferOptions::default()).unwrap();
+        assert_eq!(inference.schema, make_schema());
+        assert_eq!(inference.rows_sampled, 5);
+        assert!(inference.fields.iter().all(|f| f.confidence == 1.0));
+
+        let inference =
+            infer_schema("tests/fixtures/with_nulls.csv", &InferOptions::default()).unwrap();
+        assert_eq!(inference.schema, make_schema());
+        let nulls: Vec<usize> = inference.fields.iter().map(|f| f.null_count).collect();
+        assert_eq!(nulls, vec![3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_infer_schema_confidence() {
+        let inference = infer_schema("tests/fixtures/mixed.csv", &InferOptions::default()).unwrap();
+        let dtypes: Vec<DataType> = inference.fields.iter().map(|f| f.dtype.clone()).collect();
+        assert_eq!(
+            dtypes,
+            vec![DataType::Str, DataType::Str, DataType::Str, DataType::Str]
+        );
+        // no non-null values to go on
+        assert_eq!(inference.fields[3].confidence, 0.0);
+
+        let options = InferOptions {
+            min_confidence: 0.7,
+            ..InferOptions::default()
+        };
+        let inference = infer_schema("tests/fixtures/mixed.csv", &options).unwrap();
+        let dtypes: Vec<(DataType, f64)> = inference
+            .fields
+            .iter()
+            .map(|f| (f.dtype.clone(), f.confidence))
+            .collect();
+        assert_eq!(
+            dtypes,
+            vec![
+                (DataType::Int, 0.75),
+                (DataType::Float, 0.75),
+                (DataType::Bool, 0.75),
+                (DataType::Str, 0.0),
+            ]
+        );
+
+        // the cells that kept confidence below 1.0 are nulled out, not fatal
+        let dataset = Dataset::from_csv_infer("tests/fixtures/mixed.csv", &options).unwrap();
+        assert_eq!(dataset.shape(), (4, 4));
+        let coerced: Vec<(usize, &str, Option<&str>)> = dataset
+            .coercions
+            .iter()
+            .map(|c| (c.row, c.column.as_str(), c.value.as_deref()))
+            .collect();
+        assert_eq!(
+            coerced,
+            vec![
+                (2, "code", Some("x")),
+                (3, "amount", Some("n/a")),
+                (2, "flag", Some("maybe")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_sample_rows() {
+        let options = InferOptions {
+            sample_rows: Some(2),
+            ..InferOptions::default()
+        };
+        let inference = infer_schema("tests/fixtures/mixed.csv", &options).unwrap();
+        assert_eq!(inference.rows_sampled, 2);
+        assert_eq!(
+            inference.schema,
+            Schema::new(vec![
+                Field::new("code", DataType::Int),
+                Field::new("amount", DataType::Float),
+                Field::new("flag", DataType::Bool),
+                Field::new("empty", DataType::Str),
+            ])
+        );
+        // the third row doesn't parse with a schema inferred from the first two
+        assert!(Dataset::from_csv_infer("tests/fixtures/mixed.csv", &options).is_err());
+    }
+
+    #[test]
+    fn test_from_csv_infer() {
+        let dataset =
+            Dataset::from_csv_infer("tests/fixtures/all_types.csv", &InferOptions::default())
+                .unwrap();
+        assert_eq!(dataset.shape(), (5, 4));
+        assert!(matches!(
+            dataset.get_column_by_name("score"),
+            Some(Column::Float(_))
+        ));
+        assert!(matches!(
+            dataset.get_column_by_name("active"),
+            Some(Column::Bool(_))
+        ));
+    }
+    fn events_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("day", DataType::Date),
+            Field::new("at", DataType::DateTime),
+            Field::new("reading", DataType::Float),
+        ])
+    }
+
+    fn rendered(column: &Column) -> Vec<Option<String>> {
+        (0..column.len()).map(|i| column.format_value(i)).collect()
+    }
+
+    #[test]
+    fn test_load_csv_dates() {
+        let dataset = Dataset::from_csv("tests/fixtures/events.csv", &events_schema()).unwrap();
+        assert_eq!(dataset.shape(), (7, 4));
+        let day = dataset.get_column_by_name("day").unwrap();
+        assert_eq!(day.dtype(), DataType::Date);
+        assert_eq!(day.null_count(), 1);
+        assert_eq!(day.format_value(1).as_deref(), Some("2024-01-06"));
+        let at = rendered(dataset.get_column_by_name("at").unwrap());
+        assert_eq!(
+            at[..5],
+            [
+                Some("2024-01-01T09:00:00+00:00".to_string()),
+                // no offset in the file: UTC
+                Some("2024-01-06T10:30:00+00:00".to_string()),
+                Some("2024-01-08T12:00:00+02:00".to_string()),
+                None,
+                Some("2024-01-07T08:00:00.250+00:00".to_string()),
+            ]
+        );
+
+        let options = CsvOptions {
+            timezone: Some("+02:00".parse().unwrap()),
+            ..CsvOptions::default()
+        };
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/events.csv", &events_schema(), &options)
+                .unwrap();
+        let at = rendered(dataset.get_column_by_name("at").unwrap());
+        assert_eq!(at[0].as_deref(), Some("2024-01-01T09:00:00+00:00"));
+        assert_eq!(at[1].as_deref(), Some("2024-01-06T10:30:00+02:00"));
+    }
+
+    #[test]
+    fn test_load_csv_date_formats() {
+        let schema = Schema::new(vec![
+            Field::new("day", DataType::Date),
+            Field::new("at", DataType::DateTime),
+        ]);
+        match Dataset::from_csv("tests/fixtures/events_eu.csv", &schema)
+            .err()
+            .unwrap()
+        {
+            CsvLoadingError::ParseError { column, row, .. } => {
+                assert_eq!(column, "day");
+                assert_eq!(row, 0);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let options = CsvOptions {
+            date_formats: vec!["%d/%m/%Y".to_string(), "%Y-%m-%d".to_string()],
+            datetime_formats: vec!["%d/%m/%Y %H:%M".to_string()],
+            ..CsvOptions::default()
+        };
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/events_eu.csv", &schema, &options)
+                .unwrap();
+        assert_eq!(
+            rendered(dataset.get_column_by_name("day").unwrap()),
+            vec![
+                Some("2024-01-31".to_string()),
+                Some("2024-02-01".to_string())
+            ]
+        );
+        assert_eq!(
+            rendered(dataset.get_column_by_name("at").unwrap()),
+            vec![
+                Some("2024-01-31T09:30:00+00:00".to_string()),
+                Some("2024-02-01T18:00:00+00:00".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_dates() {
+        let inference =
+            infer_schema("tests/fixtures/events.csv", &InferOptions::default()).unwrap();
+        assert_eq!(inference.schema, events_schema());
+    }
+
+    #[test]
+    fn test_validate_csv_ordering() {
+        let rules = vec![
+            Rule::new("at", Constraint::Increasing { strict: true }),
+            Rule::new("day", Constraint::Increasing { strict: false }),
+            Rule::new("reading", Constraint::Increasing { strict: false }),
+            Rule::new("reading", Constraint::Increasing { strict: true }),
+            Rule::new("id", Constraint::Decreasing { strict: false }),
+        ];
+        let dataset = Dataset::from_csv("tests/fixtures/events.csv", &events_schema()).unwrap();
+        let expected = validate(&dataset, &rules);
+        let failed: Vec<Vec<usize>> = expected.iter().map(|r| r.failed_rows.clone()).collect();
+        assert_eq!(
+            failed,
+            vec![
+                vec![3, 4],
+                vec![2, 4],
+                vec![4, 5],
+                vec![2, 4, 5],
+                vec![1, 2, 3, 4, 5, 6],
+            ]
+        );
+
+        for batch_size in [1, 2, 3, 4, 7] {
+            let report = validate_csv(
+                "tests/fixtures/events.csv",
+                &events_schema(),
+                &rules,
+                &CsvOptions::default(),
+                &ValidationOptions::default(),
+                batch_size,
+            )
+            .unwrap();
+            assert_same_report(&report, &expected);
+
+            // every batch validated on its own, then merged in order
+            let merged = CsvBatches::open(
+                "tests/fixtures/events.csv",
+                &events_schema(),
+                &CsvOptions::default(),
+                batch_size,
+            )
+            .unwrap()
+            .map(|batch| {
+                let mut validator = StreamingValidator::new(&rules, ValidationOptions::default());
+                validator.update(&batch.unwrap());
+                validator
+            })
+            .reduce(|mut acc, next| {
+                acc.merge(next);
+                acc
+            })
+            .unwrap();
+            assert_same_report(&merged.finish(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_csv_datetime_keys() {
+        // rows 0 and 1, and rows 2 and 3, are the same instants in different offsets
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("site", DataType::Str),
+            Field::new("start", DataType::DateTime),
         ]);
-        let result = Dataset::from_csv("tests/fixtures/all_types.csv", &schema);
-        assert!(result.is_err());
+        let rules = vec![
+            Rule::new("start", Constraint::Unique),
+            Rule::new("start", Constraint::UniqueWith(vec!["site".to_string()])),
+            Rule::new("start", Constraint::DistinctCountBetween { min: 3, max: 4 }),
+        ];
+        let dataset = Dataset::from_csv("tests/fixtures/shifts.csv", &schema).unwrap();
+        let expected = validate(&dataset, &rules);
+        let failed: Vec<Vec<usize>> = expected.iter().map(|r| r.failed_rows.clone()).collect();
+        assert_eq!(failed, vec![vec![1, 3], vec![1], vec![]]);
+        assert_eq!(expected[2].observed, Some(2.0));
+        assert_eq!(
+            expected[0].sample_values,
+            vec!["2024-03-01T11:00:00+01:00", "2024-03-01T07:00:00-05:00"]
+        );
+
+        for batch_size in [1, 2, 4] {
+            let report = validate_csv(
+                "tests/fixtures/shifts.csv",
+                &schema,
+                &rules,
+                &CsvOptions::default(),
+                &ValidationOptions::default(),
+                batch_size,
+            )
+            .unwrap();
+            assert_same_report(&report, &expected);
+
+            let merged = CsvBatches::open(
+                "tests/fixtures/shifts.csv",
+                &schema,
+                &CsvOptions::default(),
+                batch_size,
+            )
+            .unwrap()
+            .map(|batch| {
+                let mut validator = StreamingValidator::new(&rules, ValidationOptions::default());
+                validator.update(&batch.unwrap());
+                validator
+            })
+            .reduce(|mut acc, next| {
+                acc.merge(next);
+                acc
+            })
+            .unwrap();
+            assert_same_report(&merged.finish(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_load_csv_decimal() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("amount", DataType::Decimal),
+        ]);
+        match Dataset::from_csv("tests/fixtures/ledger.csv", &schema)
+            .err()
+            .unwrap()
+        {
+            CsvLoadingError::ParseError { column, row, .. } => {
+                assert_eq!(column, "amount");
+                assert_eq!(row, 4);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let options = CsvOptions {
+            lenient: true,
+            ..CsvOptions::default()
+        };
+        let dataset =
+            Dataset::from_csv_with_options("tests/fixtures/ledger.csv", &schema, &options).unwrap();
+        let amount = dataset.get_column_by_name("amount").unwrap();
+        assert_eq!(amount.format_value(0).as_deref(), Some("10.00"));
+        assert_eq!(amount.sum(), Some(6.595));
+        assert_eq!(dataset.coercions.len(), 1);
+
+        let rules = vec![
+            Rule::new("amount", Constraint::MaxScale(2)),
+            Rule::new(
+                "amount",
+                Constraint::Precision {
+                    precision: 3,
+                    scale: 2,
+                },
+            ),
+        ];
+        let results = validate(&dataset, &rules);
+        assert_eq!(results[0].failed_rows, vec![2, 3, 4]);
+        assert_eq!(results[1].failed_rows, vec![0, 2, 3, 4]);
+    }
+
+    fn orders_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("customer", DataType::Str),
+            Field::new("amount", DataType::Float),
+            Field::new("status", DataType::Str),
+        ])
+    }
+
+    fn orders_rules() -> Vec<Rule> {
+        vec![
+            Rule::new("id", Constraint::Unique),
+            Rule::new(
+                "customer",
+                Constraint::UniqueWith(vec!["status".to_string()]),
+            )
+            .severity(Severity::Warning),
+            Rule::new("customer", Constraint::NotNull).max_failures(1),
+            Rule::new("amount", Constraint::GreaterThan(0.0)).when(
+                "status",
+                Constraint::InSet(InSetValues::StrSet(vec!["paid".to_string()])),
+            ),
+            Rule::new(
+                "amount",
+                Constraint::Between {
+                    min: 0.0,
+                    max: 150.0,
+                },
+            ),
+            Rule::new("discount", Constraint::NotNull),
+            Rule::new(
+                "customer",
+                Constraint::UniqueWith(vec!["status".to_string()]),
+            )
+            .null_policy(NullPolicy::Exclude),
+            Rule::new("amount", Constraint::Increasing { strict: false })
+                .null_policy(NullPolicy::Pass),
+            Rule::new("id", Constraint::SumEqual(60.0)),
+            Rule::new(
+                "amount",
+                Constraint::MedianBetween {
+                    min: 50.0,
+                    max: 100.0,
+                },
+            ),
+            Rule::new(
+                "amount",
+                Constraint::MaxBetween {
+                    min: 0.0,
+                    max: 500.0,
+                },
+            ),
+            Rule::new(
+                "customer",
+                Constraint::DistinctCountBetween { min: 1, max: 4 },
+            ),
+            Rule::new("customer", Constraint::NullRatioAtMost(0.1)),
+            Rule::new("id", Constraint::RowCountBetween { min: 1, max: 5 }).when(
+                "status",
+                Constraint::InSet(InSetValues::StrSet(vec!["paid".to_string()])),
+            ),
+        ]
+    }
+
+    fn assert_same_report(actual: &Report, expected: &Report) {
+        assert_eq!(actual.shape, expected.shape);
+        assert_eq!(actual.len(), expected.len());
+        assert_eq!(actual.rule_count, expected.rule_count);
+        for (a, e) in actual.iter().zip(expected) {
+            assert_eq!(a.to_string(), e.to_string());
+            assert_eq!(a.evaluated_count, e.evaluated_count);
+            assert_eq!(a.failed_rows, e.failed_rows);
+            assert_eq!(a.sample_values, e.sample_values);
+        }
+    }
+
+    #[test]
+    fn test_csv_batches() {
+        let batches: Vec<Dataset> = CsvBatches::open(
+            "tests/fixtures/orders.csv",
+            &orders_schema(),
+            &CsvOptions::default(),
+            5,
+        )
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+        let rows: Vec<usize> = batches.iter().map(|b| b.shape().0).collect();
+        assert_eq!(rows, vec![5, 5, 2]);
+        assert_eq!(batches[1].headers, batches[0].headers);
+
+        // no trailing empty batch when the row count divides evenly
+        let batches: Vec<_> = CsvBatches::open(
+            "tests/fixtures/orders.csv",
+            &orders_schema(),
+            &CsvOptions::default(),
+            12,
+        )
+        .unwrap()
+        .collect();
+        assert_eq!(batches.len(), 1);
+    }
+
+    #[test]
+    fn test_csv_batches_parse_error_row() {
+        let mut batches = CsvBatches::open(
+            "tests/fixtures/dirty.csv",
+            &dirty_schema(),
+            &CsvOptions::default(),
+            1,
+        )
+        .unwrap();
+        assert!(batches.next().unwrap().is_ok());
+        match batches.next().unwrap().err().unwrap() {
+            CsvLoadingError::ParseError { column, row, .. } => {
+                assert_eq!(column, "id");
+                assert_eq!(row, 1);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+        assert!(batches.next().is_none());
+    }
+
+    #[test]
+    fn test_validate_csv_matches_validate() {
+        let dataset = Dataset::from_csv("tests/fixtures/orders.csv", &orders_schema()).unwrap();
+        let options = ValidationOptions {
+            max_failed_rows: 2,
+            max_sample_values: 1,
+        };
+        let expected = validate_with_options(&dataset, &orders_rules(), &options);
+        assert_eq!(expected[0].failed_rows, vec![4, 8]);
+        assert!(expected[5].error.is_some());
+        let observed: Vec<Option<f64>> = expected.iter().skip(8).map(|r| r.observed).collect();
+        assert_eq!(
+            observed,
+            vec![
+                Some(60.0),
+                Some(42.0),
+                Some(200.0),
+                Some(5.0),
+                Some(1.0 / 12.0),
+                Some(6.0)
+            ]
+        );
+
+        for batch_size in [1, 2, 3, 5, 12, 100] {
+            let report = validate_csv(
+                "tests/fixtures/orders.csv",
+                &orders_schema(),
+                &orders_rules(),
+                &CsvOptions::default(),
+                &options,
+                batch_size,
+            )
+            .unwrap();
+            assert_same_report(&report, &expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_csv_lenient() {
+        let csv_options = CsvOptions {
+            lenient: true,
+            ..CsvOptions::default()
+        };
+        let rules = vec![Rule::new("id", Constraint::NotNull)];
+        let dataset = Dataset::from_csv_with_options(
+            "tests/fixtures/dirty.csv",
+            &dirty_schema(),
+            &csv_options,
+        )
+        .unwrap();
+        let expected = validate(&dataset, &rules);
+
+        for batch_size in [1, 2, 4] {
+            let report = validate_csv(
+                "tests/fixtures/dirty.csv",
+                &dirty_schema(),
+                &rules,
+                &csv_options,
+                &ValidationOptions::default(),
+                batch_size,
+            )
+            .unwrap();
+            assert_same_report(&report, &expected);
+        }
+    }
+
+    #[test]
+    fn test_streaming_validator_merge() {
+        let dataset = Dataset::from_csv("tests/fixtures/orders.csv", &orders_schema()).unwrap();
+        let expected = validate(&dataset, &orders_rules());
+
+        // validate every batch on its own, then merge pairwise
+        let mut partials: Vec<StreamingValidator> = CsvBatches::open(
+            "tests/fixtures/orders.csv",
+            &orders_schema(),
+            &CsvOptions::default(),
+            3,
+        )
+        .unwrap()
+        .map(|batch| {
+            let mut validator =
+                StreamingValidator::new(&orders_rules(), ValidationOptions::default());
+            validator.update(&batch.unwrap());
+            validator
+        })
+        .collect();
+        assert_eq!(partials.len(), 4);
+        let right = partials.split_off(2);
+        let merge_all = |validators: Vec<StreamingValidator>| {
+            validators
+                .into_iter()
+                .reduce(|mut acc, next| {
+                    acc.merge(next);
+                    acc
+                })
+                .unwrap()
+        };
+        let mut left = merge_all(partials);
+        left.merge(merge_all(right));
+        assert_eq!(left.rows(), 12);
+
+        assert_same_report(&left.finish(), &expected);
+    }
+
+    #[test]
+    fn test_validate_csv_categorical() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int),
+            Field::new("customer", DataType::Categorical),
+            Field::new("amount", DataType::Float),
+            Field::new("status", DataType::Categorical),
+        ]);
+        let dataset = Dataset::from_csv("tests/fixtures/orders.csv", &schema).unwrap();
+        match dataset.get_column_by_name("status") {
+            Some(Column::Categorical(col)) => assert!(col.dictionary.len() < col.len()),
+            _ => panic!("status should load as Categorical"),
+        }
+
+        let expected = validate(
+            &Dataset::from_csv("tests/fixtures/orders.csv", &orders_schema()).unwrap(),
+            &orders_rules(),
+        );
+        assert_same_report(&validate(&dataset, &orders_rules()), &expected);
+        for batch_size in [1, 5] {
+            let report = validate_csv(
+                "tests/fixtures/orders.csv",
+                &schema,
+                &orders_rules(),
+                &CsvOptions::default(),
+                &ValidationOptions::default(),
+                batch_size,
+            )
+            .unwrap();
+            assert_same_report(&report, &expected);
+        }
+    }
+
+    #[test]
+    fn test_streaming_column_stats() {
+        let dataset = Dataset::from_csv("tests/fixtures/orders.csv", &orders_schema()).unwrap();
+        let amount = dataset.get_column_by_name("amount").unwrap();
+
+        let mut validator = StreamingValidator::new(&[], ValidationOptions::default());
+        for batch in CsvBatches::open(
+            "tests/fixtures/orders.csv",
+            &orders_schema(),
+            &CsvOptions::default(),
+            5,
+        )
+        .unwrap()
+        {
+            validator.update(&batch.unwrap());
+        }
+        let stats = validator.column_stats("amount").unwrap();
+        assert_eq!(stats.count, 12);
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.min(), amount.min());
+        assert_eq!(stats.max(), amount.max());
+        assert!((stats.sum().unwrap() - amount.sum().unwrap()).abs() < 1e-9);
+        assert!((stats.mean().unwrap() - amount.mean().unwrap()).abs() < 1e-9);
+        assert!((stats.std().unwrap() - amount.std().unwrap()).abs() < 1e-9);
+
+        let customer = validator.column_stats("customer").unwrap();
+        assert_eq!(customer.not_null_count(), 11);
+        assert_eq!(customer.mean(), None);
+        assert_eq!(
+            ColumnStats::from_column(dataset.get_column_by_name("id").unwrap()).max(),
+            Some(10.0)
+        );
+    }
+}
+
+#[cfg(feature = "json")]
+mod suite_tests {
+    use verdict_core::{
+        dataset::InSetValues,
+        dataset::{DataType, Field, Schema},
+        rules::{Constraint, NullPolicy, Rule, Severity},
+        suite::{SuiteError, SuiteFormat, load_rules, parse_rules, parse_schema, rules_to_string},
+    };
+
+    fn make_rules() -> Vec<Rule> {
+        vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("score", Constraint::Between { min: 0.0, max: 1.5 })
+                .mostly(0.99)
+                .severity(Severity::Warning)
+                .null_policy(NullPolicy::Exclude),
+            Rule::new(
+                "code",
+                Constraint::InSet(InSetValues::IntSet(vec![1, 2, 3])),
+            ),
+            Rule::new("ratio", Constraint::InSet(InSetValues::FloatSet(vec![0.5]))),
+            Rule::new("zip", Constraint::MatchesRegex(r"^\d{5}$".to_string())).when(
+                "country",
+                Constraint::InSet(InSetValues::StrSet(vec!["US".to_string()])),
+            ),
+            Rule::new("end", Constraint::GreaterThanColumn("start".to_string())).max_failures(3),
+            Rule::new(
+                "day",
+                Constraint::DateBetween {
+                    min: "2024-01-01".parse().unwrap(),
+                    max: "2024-12-31".parse().unwrap(),
+                },
+            ),
+            Rule::new(
+                "updated_at",
+                Constraint::Freshness {
+                    max_age_secs: 86400,
+                    reference: Some("2024-06-01T00:00:00+02:00".parse().unwrap()),
+                },
+            ),
+            Rule::new("updated_at", Constraint::Increasing { strict: true }),
+            Rule::new("total", Constraint::MaxScale(2)),
+            Rule::new(
+                "total",
+                Constraint::Precision {
+                    precision: 12,
+                    scale: 2,
+                },
+            ),
+            Rule::new(
+                "sku",
+                Constraint::All(vec![
+                    Constraint::StartsWith("SKU-".to_string()),
+                    Constraint::Not(Box::new(Constraint::InSet(InSetValues::StrSet(vec![
+                        "SKU-test".to_string(),
+                    ])))),
+                ]),
+            )
+            .when(
+                "price",
+                Constraint::Any(vec![Constraint::NotNull, Constraint::Equal(0.0)]),
+            ),
+            Rule::new(
+                "price",
+                Constraint::MeanBetween {
+                    min: 1.0,
+                    max: 50.0,
+                },
+            ),
+            Rule::new("sku", Constraint::DistinctCountBetween { min: 1, max: 100 }),
+        ]
+    }
+
+    #[test]
+    fn test_parse_json_suite() {
+        let input = r#"[
+            {"column": "id", "constraint": "not_null"},
+            {"column": "age", "constraint": {"greater_than": 0}, "severity": "warning"},
+            {"column": "age", "constraint": {"less_than": 150}, "null_policy": "pass"}
+        ]"#;
+        let rules = parse_rules(input, SuiteFormat::Json).unwrap();
+        assert_eq!(rules[0], Rule::new("id", Constraint::NotNull));
+        assert_eq!(
+            rules[1],
+            Rule::new("age", Constraint::GreaterThan(0.0)).severity(Severity::Warning)
+        );
+        assert_eq!(rules[2].null_policy, NullPolicy::Pass);
+    }
+
+    #[test]
+    fn test_parse_date_constraints() {
+        let input = r#"[
+            {"column": "day", "constraint": {"date_between": {"min": "2024-01-01", "max": "2024-01-31"}}},
+            {"column": "at", "constraint": "not_in_future"},
+            {"column": "at", "constraint": {"freshness": {"max_age_secs": 3600}}},
+            {"column": "at", "constraint": {"increasing": {}}}
+        ]"#;
+        let rules = parse_rules(input, SuiteFormat::Json).unwrap();
+        assert_eq!(
+            rules[0].constraint,
+            Constraint::DateBetween {
+                min: "2024-01-01".parse().unwrap(),
+                max: "2024-01-31".parse().unwrap(),
+            }
+        );
+        assert_eq!(rules[1].constraint, Constraint::NotInFuture);
+        assert_eq!(
+            rules[2].constraint,
+            Constraint::Freshness {
+                max_age_secs: 3600,
+                reference: None,
+            }
+        );
+        assert_eq!(
+            rules[3].constraint,
+            Constraint::Increasing { strict: false }
+        );
+    }
+
+    #[test]
+    fn test_parse_combinators() {
+        let input = r#"[
+            {"column": "price", "constraint": {"any": [
+                {"not": "not_null"},
+                {"between": {"min": 0, "max": 100}}
+            ]}}
+        ]"#;
+        let rules = parse_rules(input, SuiteFormat::Json).unwrap();
+        assert_eq!(
+            rules[0].constraint,
+            Constraint::Any(vec![
+                Constraint::Not(Box::new(Constraint::NotNull)),
+                Constraint::Between {
+                    min: 0.0,
+                    max: 100.0
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let rules = make_rules();
+        let text = rules_to_string(&rules, SuiteFormat::Json).unwrap();
+        assert_eq!(parse_rules(&text, SuiteFormat::Json).unwrap(), rules);
+    }
+
+    #[test]
+    fn test_invalid_rule_points_at_entry() {
+        let input = r#"{"rules": [
+            {"column": "id", "constraint": "not_null"},
+            {"column": "age", "constraint": {"greater": 0}}
+        ]}"#;
+        let err = parse_rules(input, SuiteFormat::Json).unwrap_err();
+        assert!(matches!(err, SuiteError::InvalidRule { index: 1, .. }));
+        let message = err.to_string();
+        assert!(
+            message
+                .starts_with("Invalid rule at rules[1] (column 'age'): unknown variant `greater`")
+        );
+    }
+
+    #[test]
+    fn test_invalid_mostly_points_at_entry() {
+        let input = r#"[
+            {"column": "id", "constraint": "not_null", "tolerance": {"mostly": 0.99}},
+            {"column": "age", "constraint": "not_null", "tolerance": {"mostly": 99}}
+        ]"#;
+        let err = parse_rules(input, SuiteFormat::Json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid rule at rules[1] (column 'age'): mostly must be between 0.0 and 1.0, got 99"
+        );
+    }
+
+    #[test]
+    fn test_unknown_rule_field_is_rejected() {
+        let input = r#"[{"column": "id", "constraint": "not_null", "severty": "info"}]"#;
+        let err = parse_rules(input, SuiteFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("severty"));
+    }
+
+    #[test]
+    fn test_missing_rules_list() {
+        let err = parse_rules(r#"{"checks": []}"#, SuiteFormat::Json).unwrap_err();
+        assert!(matches!(err, SuiteError::MissingRules));
+    }
+
+    #[test]
+    fn test_unsupported_extension() {
+        let err = load_rules("tests/fixtures/all_types.csv").unwrap_err();
+        assert!(matches!(err, SuiteError::UnsupportedFormat { .. }));
+    }
+
+    #[test]
+    fn test_parse_schema() {
+        let input = r#"{"fields": [
+            {"name": "id", "dtype": "int"},
+            {"name": "name", "dtype": "string"},
+            {"name": "active", "dtype": "bool"}
+        ]}"#;
+        let schema = parse_schema(input, SuiteFormat::Json).unwrap();
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int),
+                Field::new("name", DataType::Str),
+                Field::new("active", DataType::Bool),
+            ])
+        );
+
+        let err = parse_schema(
+            r#"{"fields": [{"name": "id", "dtype": "uuid"}]}"#,
+            SuiteFormat::Json,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SuiteError::InvalidSchema { .. }));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_load_yaml_suite() {
+        let rules = load_rules("tests/fixtures/rules.yaml").unwrap();
+        assert_eq!(rules.len(), 5);
+        assert_eq!(
+            rules[2],
+            Rule::new(
+                "score",
+                Constraint::Between {
+                    min: 0.0,
+                    max: 100.0
+                }
+            )
+            .mostly(0.9)
+            .severity(Severity::Warning)
+        );
+        assert_eq!(
+            rules[4],
+            Rule::new("name", Constraint::MatchesRegex("^[a-z]+$".to_string()))
+                .when("active", Constraint::NotNull)
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_round_trip() {
+        let rules = make_rules();
+        let text = rules_to_string(&rules, SuiteFormat::Yaml).unwrap();
+        assert_eq!(parse_rules(&text, SuiteFormat::Yaml).unwrap(), rules);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_load_toml_suite() {
+        let rules = load_rules("tests/fixtures/rules.toml").unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                Rule::new("id", Constraint::NotNull),
+                Rule::new("score", Constraint::GreaterThan(50.0))
+                    .severity(Severity::Info)
+                    .max_failures(1),
+            ]
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_round_trip() {
+        let rules = make_rules();
+        let text = rules_to_string(&rules, SuiteFormat::Toml).unwrap();
+        assert_eq!(parse_rules(&text, SuiteFormat::Toml).unwrap(), rules);
+    }
+}
+
+#[cfg(feature = "formats")]
+mod format_tests {
+    use std::time::Duration;
+
+    use verdict_core::{
+        dataset::{Column, Dataset, IntColumn, StrColumn},
+        report::{JSON_SCHEMA_VERSION, Report},
+        rules::{Constraint, Rule, Severity, validate},
+    };
+
+    fn make_report() -> Report {
+        let data = Dataset::new(
+            vec!["id".to_string(), "name".to_string()],
+            vec![
+                Column::Int(IntColumn::from(vec![Some(1), Some(2), Some(-3)])),
+                Column::Str(StrColumn::from(vec![
+                    Some("a|b".to_string()),
+                    None,
+                    Some("<c>".to_string()),
+                ])),
+            ],
+        );
+        let rules = vec![
+            Rule::new("id", Constraint::NotNull),
+            Rule::new("id", Constraint::GreaterThan(0.0)),
+            Rule::new("name", Constraint::NotNull).severity(Severity::Warning),
+        ];
+        let mut report = validate(&data, &rules);
+        report.elapsed = Duration::from_millis(250);
+        report
+    }
+
+    #[test]
+    fn test_report_to_json() {
+        let doc = make_report().to_json_value();
+        assert_eq!(doc["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(doc["verdict"], "failed");
+        assert_eq!(doc["shape"]["rows"], 3);
+        assert_eq!(doc["elapsed_ms"], 250.0);
+        assert_eq!(doc["summary"]["rules"], 3);
+        assert_eq!(doc["summary"]["load_findings"], 0);
+        assert_eq!(doc["summary"]["failed"], 1);
+        assert_eq!(doc["summary"]["warnings"], 1);
+
+        let failed = &doc["results"][1];
+        assert_eq!(failed["status"], "fail");
+        assert_eq!(failed["severity"], "error");
+        assert_eq!(failed["failed_rows"], serde_json::json!([2]));
+        assert_eq!(failed["sample_values"], serde_json::json!(["-3"]));
+        assert!(failed["tolerance"].is_null());
+        assert_eq!(doc["results"][2]["status"], "warn");
+        assert!(doc["results"][0]["error"].is_null());
+        assert!(doc["results"][0]["observed"].is_null());
+    }
+
+    #[test]
+    fn test_report_to_junit() {
+        let xml = make_report().to_junit();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(
+            "<testsuite name=\"verdict\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"0\" time=\"0.250000\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"id\" name=\"not null\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"values not greater than 0\" type=\"error\">FAIL: column &apos;id&apos;"
+        ));
+        // warnings are reported without failing the test case
+        assert_eq!(xml.matches("<failure").count(), 1);
+        assert!(xml.contains("<system-out>WARN: column &apos;name&apos;"));
+    }
+
+    #[test]
+    fn test_report_to_junit_replaces_control_characters() {
+        let data = Dataset::new(
+            vec!["name".to_string()],
+            vec![Column::Str(StrColumn::from(vec![Some(
+                "a\u{1}b\tc".to_string(),
+            )]))],
+        );
+        let xml = validate(
+            &data,
+            &[Rule::new("name", Constraint::StartsWith("z".to_string()))],
+        )
+        .to_junit();
+        assert!(!xml.contains('\u{1}'));
+        assert!(xml.contains("a\u{fffd}b\tc"));
+    }
+
+    #[test]
+    fn test_report_to_markdown() {
+        let md = make_report().to_markdown();
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(lines[0], "### Verdict: FAILED");
+        assert_eq!(
+            lines[2],
+            "3 rows × 2 columns, 3 rules: 1 passed, 1 failed, 1 warnings, 0 info"
+        );
+        assert_eq!(
+            lines[4],
+            "| Status | Column | Constraint | Failed | Details |"
+        );
+        assert_eq!(lines[6], "| PASS | `id` | `not null` | 0/3 |  |");
+        assert_eq!(
+            lines[7],
+            "| FAIL | `id` | `> 0` | 1/3 | values not greater than 0; sample: -3 |"
+        );
+        assert!(lines[8].starts_with("| WARN | `name` | `not null` | 1/3 |"));
+    }
+}
+
+#[cfg(feature = "parallel")]
+mod parallel_tests {
+    use verdict_core::{
+        dataset::{Column, Dataset, FloatColumn, IntColumn, StrColumn},
+        rules::{Constraint, Rule, validate},
+    };
+
+    const ROWS: usize = 200_000;
+
+    fn make_large_dataset() -> Dataset {
+        let mut ids: Vec<Option<i64>> = (0..ROWS as i64).map(Some).collect();
+        ids[150_000] = Some(10);
+        let values: Vec<Option<f64>> = (0..ROWS)
+            .map(|i| (i % 1000 != 999).then_some((i % 500) as f64))
+            .collect();
+        let names: Vec<Option<String>> = (0..ROWS).map(|i| Some(format!("row{}", i))).collect();
+        Dataset::new(
+            vec!["id".to_string(), "value".to_string(), "name".to_string()],
+            vec![
+                Column::Int(IntColumn::from(ids)),
+                Column::Float(FloatColumn::from(values)),
+                Column::Str(StrColumn::from(names)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_parallel_results_match_sequential_order() {
+        let dataset = make_large_dataset();
+        let rules = vec![
+            Rule::new("value", Constraint::GreaterThan(0.0)),
+            Rule::new("id", Constraint::Unique),
+            Rule::new("name", Constraint::StartsWith("row1".to_string()))
+                .when("value", Constraint::GreaterThan(400.0)),
+            Rule::new("value", Constraint::NotNull).max_failures(200),
+            Rule::new("missing", Constraint::NotNull),
+            Rule::new("id", Constraint::LessThanColumn("value".to_string())),
+        ];
+        let report = validate(&dataset, &rules);
+
+        let constraints: Vec<&str> = report.iter().map(|r| r.constraint.as_str()).collect();
+        assert_eq!(
+            constraints,
+            vec![
+                "> 0",
+                "unique",
+                "starts with 'row1' when 'value' > 400",
+                "not null",
+                "not null",
+                "< column 'value'",
+            ]
+        );
+
+        let value = dataset.get_column_by_name("value").unwrap();
+        let expected: Vec<usize> = value
+            .gt(0.0)
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !matches!(v, Some(true)))
+            .map(|(row, _)| row)
+            .collect();
+        assert_eq!(report[0].failed_count, expected.len());
+        assert_eq!(report[0].failed_rows, expected[..100]);
+
+        assert_eq!(report[1].failed_rows, vec![150_000]);
+        assert!(report[2].failed_rows.iter().all(|row| row % 500 > 400));
+        assert!(report[3].passed);
+        assert!(report[4].error.is_some());
+        assert_eq!(report[5].failed_count, ROWS);
+
+        // repeated runs are deterministic
+        let again = validate(&dataset, &rules);
+        for (a, b) in report.iter().zip(&again) {
+            assert_eq!(a.to_string(), b.to_string());
+        }
     }
 }
@@ -1,11 +1,23 @@
-use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use rust_decimal::Decimal;
 use verdict_core::{
-    csv_loader::DatasetCsvExt,
+    csv_loader::{self, CsvOptions, DatasetCsvExt, InferOptions},
     dataset::{
-        BoolColumn, Column, DataType, Dataset, Field, FloatColumn, InSetValues, IntColumn, Schema,
-        StrColumn,
+        BoolColumn, CategoricalColumn, Column, DataType, Dataset, DateColumn, DateTimeColumn,
+        DecimalColumn, ExtraColumns, Field, FloatColumn, InSetValues, IntColumn, Schema, StrColumn,
+    },
+    report::Report,
+    rules::{
+        Constraint, NullPolicy, Rule, Severity, Tolerance, ValidationOptions, ValidationResult,
+        Verdict, check_rules as core_check_rules, validate_with_options,
     },
-    rules::{Constraint, Rule, ValidationResult, validate},
+    suite,
 };
 
 fn format_values<T>(values: &[Option<T>], fmt: impl Fn(&T) -> String) -> String {
@@ -40,7 +52,7 @@ struct PyDataset {
 impl PyColumn {
     #[staticmethod]
     fn integer(values: Vec<Option<i64>>) -> PyColumn {
-        let column = IntColumn(values);
+        let column = IntColumn::from(values);
         PyColumn {
             inner: Column::Int(column),
         }
@@ -48,7 +60,7 @@ impl PyColumn {
 
     #[staticmethod]
     fn floating(values: Vec<Option<f64>>) -> PyColumn {
-        let column = FloatColumn(values);
+        let column = FloatColumn::from(values);
         PyColumn {
             inner: Column::Float(column),
         }
@@ -56,7 +68,7 @@ impl PyColumn {
 
     #[staticmethod]
     fn boolean(values: Vec<Option<bool>>) -> PyColumn {
-        let column = BoolColumn(values);
+        let column = BoolColumn::from(values);
         PyColumn {
             inner: Column::Bool(column),
         }
@@ -64,12 +76,47 @@ impl PyColumn {
 
     #[staticmethod]
     fn string(values: Vec<Option<String>>) -> PyColumn {
-        let column = StrColumn(values);
+        let column = StrColumn::from(values);
         PyColumn {
             inner: Column::Str(column),
         }
     }
 
+    /// Dictionary-encoded strings, for columns with few distinct values.
+    #[staticmethod]
+    fn categorical(values: Vec<Option<String>>) -> PyColumn {
+        let column = CategoricalColumn::from_values(values);
+        PyColumn {
+            inner: Column::Categorical(column),
+        }
+    }
+
+    /// Values are `decimal.Decimal`s.
+    #[staticmethod]
+    fn decimal(values: Vec<Option<Decimal>>) -> PyColumn {
+        let column = DecimalColumn(values);
+        PyColumn {
+            inner: Column::Decimal(column),
+        }
+    }
+
+    #[staticmethod]
+    fn date(values: Vec<Option<NaiveDate>>) -> PyColumn {
+        let column = DateColumn(values);
+        PyColumn {
+            inner: Column::Date(column),
+        }
+    }
+
+    /// Values must be timezone-aware datetimes.
+    #[staticmethod]
+    fn datetime(values: Vec<Option<DateTime<FixedOffset>>>) -> PyColumn {
+        let column = DateTimeColumn(values);
+        PyColumn {
+            inner: Column::DateTime(column),
+        }
+    }
+
     fn len(&self) -> usize {
         self.inner.len()
     }
@@ -123,48 +170,75 @@ impl PyColumn {
     }
 
     fn gt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.inner.gt(compare)
+        self.inner.gt(compare).to_vec()
     }
     fn ge(&self, compare: f64) -> Vec<Option<bool>> {
-        self.inner.ge(compare)
+        self.inner.ge(compare).to_vec()
     }
 
     fn lt(&self, compare: f64) -> Vec<Option<bool>> {
-        self.inner.lt(compare)
+        self.inner.lt(compare).to_vec()
     }
 
     fn le(&self, compare: f64) -> Vec<Option<bool>> {
-        self.inner.le(compare)
+        self.inner.le(compare).to_vec()
     }
 
     fn equal(&self, py: Python<'_>, compare: Py<PyAny>) -> Vec<Option<bool>> {
         if let Ok(v) = compare.extract::<String>(py) {
-            self.inner.equal_str(&v)
+            self.inner.equal_str(&v).to_vec()
         } else if let Ok(v) = compare.extract::<f64>(py) {
-            self.inner.equal(v)
+            self.inner.equal(v).to_vec()
         } else {
             vec![None; self.inner.len()]
         }
     }
 
     fn between(&self, lower: f64, upper: f64) -> Vec<Option<bool>> {
-        self.inner.between(lower, upper)
+        self.inner.between(lower, upper).to_vec()
     }
 
     fn contains(&self, pat: &str) -> Vec<Option<bool>> {
-        self.inner.contains(pat)
+        self.inner.contains(pat).to_vec()
     }
 
     fn starts_with(&self, pat: &str) -> Vec<Option<bool>> {
-        self.inner.starts_with(pat)
+        self.inner.starts_with(pat).to_vec()
     }
 
     fn ends_with(&self, pat: &str) -> Vec<Option<bool>> {
-        self.inner.ends_with(pat)
+        self.inner.ends_with(pat).to_vec()
+    }
+
+    fn matches_regex(&self, pat: &str) -> PyResult<Vec<Option<bool>>> {
+        self.inner
+            .matches_regex(pat)
+            .map(|mask| mask.to_vec())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    fn scale(&self) -> Vec<Option<u32>> {
+        self.inner.scale()
     }
 
-    fn matches_regex(&self, pat: &str) -> Vec<Option<bool>> {
-        self.inner.matches_regex(pat)
+    fn fits_precision(&self, precision: u32, scale: u32) -> Vec<Option<bool>> {
+        self.inner.fits_precision(precision, scale).to_vec()
+    }
+
+    fn year(&self) -> Vec<Option<i32>> {
+        self.inner.year()
+    }
+
+    fn month(&self) -> Vec<Option<u32>> {
+        self.inner.month()
+    }
+
+    fn day(&self) -> Vec<Option<u32>> {
+        self.inner.day()
+    }
+
+    fn is_weekend(&self) -> Vec<Option<bool>> {
+        self.inner.is_weekend().to_vec()
     }
 
     fn str_length(&self) -> Vec<Option<usize>> {
@@ -193,18 +267,43 @@ impl PyColumn {
         } else {
             return vec![None; self.inner.len()];
         };
-        self.inner.is_in(&set)
+        self.inner.is_in(&set).to_vec()
     }
 
     fn __repr__(&self) -> String {
         let (dtype, values) = match &self.inner {
-            Column::Int(col) => ("i64", format_values(&col.0, |v: &i64| v.to_string())),
-            Column::Float(col) => ("f64", format_values(&col.0, |v: &f64| v.to_string())),
-            Column::Str(col) => (
-                "str",
-                format_values(&col.0, |v: &String| format!("\"{}\"", v)),
+            Column::Int(col) => {
+                let values: Vec<Option<i64>> = col.iter().collect();
+                ("i64", format_values(&values, |v: &i64| v.to_string()))
+            }
+            Column::Float(col) => {
+                let values: Vec<Option<f64>> = col.iter().collect();
+                ("f64", format_values(&values, |v: &f64| v.to_string()))
+            }
+            Column::Str(col) => {
+                let values: Vec<Option<&str>> = col.iter().collect();
+                (
+                    "str",
+                    format_values(&values, |v: &&str| format!("\"{}\"", v)),
+                )
+            }
+            Column::Bool(col) => {
+                let values: Vec<Option<bool>> = col.iter().collect();
+                ("bool", format_values(&values, |v: &bool| v.to_string()))
+            }
+            Column::Categorical(col) => {
+                let values: Vec<Option<&str>> = (0..col.len()).map(|i| col.get(i)).collect();
+                (
+                    "categorical",
+                    format_values(&values, |v: &&str| format!("\"{}\"", v)),
+                )
+            }
+            Column::Decimal(col) => ("decimal", format_values(&col.0, Decimal::to_string)),
+            Column::Date(col) => ("date", format_values(&col.0, NaiveDate::to_string)),
+            Column::DateTime(col) => (
+                "datetime",
+                format_values(&col.0, DateTime::<FixedOffset>::to_rfc3339),
             ),
-            Column::Bool(col) => ("bool", format_values(&col.0, |v: &bool| v.to_string())),
         };
         format!("[{}]: [{}]", dtype, values)
     }
@@ -244,6 +343,34 @@ impl PyDataType {
             inner: DataType::Bool,
         }
     }
+
+    #[staticmethod]
+    fn categorical() -> Self {
+        PyDataType {
+            inner: DataType::Categorical,
+        }
+    }
+
+    #[staticmethod]
+    fn decimal() -> Self {
+        PyDataType {
+            inner: DataType::Decimal,
+        }
+    }
+
+    #[staticmethod]
+    fn date() -> Self {
+        PyDataType {
+            inner: DataType::Date,
+        }
+    }
+
+    #[staticmethod]
+    fn datetime() -> Self {
+        PyDataType {
+            inner: DataType::DateTime,
+        }
+    }
 }
 
 #[pyclass(name = "Schema")]
@@ -254,7 +381,22 @@ struct PySchema {
 #[pymethods]
 impl PySchema {
     #[new]
-    fn new(py: Python<'_>, fields: Vec<(String, Py<PyDataType>)>) -> Self {
+    #[pyo3(signature = (fields, ordered=false, extra_columns="ignore"))]
+    fn new(
+        py: Python<'_>,
+        fields: Vec<(String, Py<PyDataType>)>,
+        ordered: bool,
+        extra_columns: &str,
+    ) -> PyResult<Self> {
+        let extra_columns = match extra_columns {
+            "ignore" => ExtraColumns::Ignore,
+            "keep" => ExtraColumns::KeepAsStr,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "extra_columns must be 'ignore' or 'keep'",
+                ));
+            }
+        };
         let core_fields = fields
             .iter()
             .map(|(name, dtype)| Field {
@@ -262,11 +404,94 @@ impl PySchema {
                 dtype: dtype.borrow(py).inner.clone(),
             })
             .collect();
-        PySchema {
-            inner: Schema {
-                fields: core_fields,
+        Ok(PySchema {
+            inner: Schema::new(core_fields)
+                .ordered(ordered)
+                .extra_columns(extra_columns),
+        })
+    }
+}
+
+#[pyclass(name = "CsvOptions")]
+#[derive(Clone)]
+struct PyCsvOptions {
+    inner: CsvOptions,
+}
+
+/// Converts a one-character Python string to the single byte the CSV reader expects.
+fn csv_byte(name: &str, value: &str) -> PyResult<u8> {
+    match value.as_bytes() {
+        [byte] => Ok(*byte),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must be a single ASCII character",
+            name
+        ))),
+    }
+}
+
+#[pymethods]
+impl PyCsvOptions {
+    #[new]
+    #[pyo3(signature = (
+        delimiter=",",
+        quote="\"",
+        escape=None,
+        has_headers=true,
+        comment=None,
+        trim=false,
+        null_values=None,
+        column_null_values=None,
+        true_values=None,
+        false_values=None,
+        date_formats=None,
+        datetime_formats=None,
+        timezone=None,
+        lenient=false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        delimiter: &str,
+        quote: &str,
+        escape: Option<&str>,
+        has_headers: bool,
+        comment: Option<&str>,
+        trim: bool,
+        null_values: Option<Vec<String>>,
+        column_null_values: Option<HashMap<String, Vec<String>>>,
+        true_values: Option<Vec<String>>,
+        false_values: Option<Vec<String>>,
+        date_formats: Option<Vec<String>>,
+        datetime_formats: Option<Vec<String>>,
+        timezone: Option<&str>,
+        lenient: bool,
+    ) -> PyResult<Self> {
+        let defaults = CsvOptions::default();
+        Ok(PyCsvOptions {
+            inner: CsvOptions {
+                delimiter: csv_byte("delimiter", delimiter)?,
+                quote: csv_byte("quote", quote)?,
+                escape: escape.map(|e| csv_byte("escape", e)).transpose()?,
+                has_headers,
+                comment: comment.map(|c| csv_byte("comment", c)).transpose()?,
+                trim,
+                null_values: null_values.unwrap_or(defaults.null_values),
+                column_null_values: column_null_values.unwrap_or_default(),
+                true_values: true_values.unwrap_or(defaults.true_values),
+                false_values: false_values.unwrap_or(defaults.false_values),
+                date_formats: date_formats.unwrap_or(defaults.date_formats),
+                datetime_formats: datetime_formats.unwrap_or(defaults.datetime_formats),
+                timezone: timezone
+                    .map(|tz| {
+                        tz.parse::<FixedOffset>().map_err(|_| {
+                            pyo3::exceptions::PyValueError::new_err(
+                                "timezone must be a UTC offset such as '+02:00'",
+                            )
+                        })
+                    })
+                    .transpose()?,
+                lenient,
             },
-        }
+        })
     }
 }
 
@@ -279,10 +504,7 @@ impl PyDataset {
             .map(|col| col.borrow(py).inner.clone())
             .collect();
         PyDataset {
-            inner: Dataset {
-                headers,
-                columns: core_columns,
-            },
+            inner: Dataset::new(headers, core_columns),
         }
     }
 
@@ -293,10 +515,67 @@ impl PyDataset {
         Ok(PyDataset { inner })
     }
 
+    #[staticmethod]
+    fn from_csv_with_options(
+        path: &str,
+        schema: &PySchema,
+        options: &PyCsvOptions,
+    ) -> PyResult<Self> {
+        let inner = Dataset::from_csv_with_options(path, &schema.inner, &options.inner)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(PyDataset { inner })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (path, sample_rows=Some(1000), min_confidence=1.0, options=None))]
+    fn from_csv_infer(
+        path: &str,
+        sample_rows: Option<usize>,
+        min_confidence: f64,
+        options: Option<&PyCsvOptions>,
+    ) -> PyResult<Self> {
+        let options = InferOptions {
+            sample_rows,
+            min_confidence,
+            csv: options.map(|o| o.inner.clone()).unwrap_or_default(),
+        };
+        let inner = Dataset::from_csv_infer(path, &options)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(PyDataset { inner })
+    }
+
     fn shape(&self) -> (usize, usize) {
         self.inner.shape()
     }
 
+    /// Cells that lenient loading turned into nulls.
+    #[getter]
+    fn coercions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let list = PyList::empty(py);
+        for coercion in &self.inner.coercions {
+            let item = PyDict::new(py);
+            item.set_item("row", coercion.row)?;
+            item.set_item("column", &coercion.column)?;
+            item.set_item("value", &coercion.value)?;
+            item.set_item("expected", format!("{:?}", coercion.expected))?;
+            list.append(item)?;
+        }
+        Ok(list)
+    }
+
+    /// Rows that lenient loading found with cells past the header.
+    #[getter]
+    fn overlong_rows<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let list = PyList::empty(py);
+        for overlong in &self.inner.overlong_rows {
+            let item = PyDict::new(py);
+            item.set_item("row", overlong.row)?;
+            item.set_item("extra", &overlong.extra)?;
+            list.append(item)?;
+        }
+        Ok(list)
+    }
+
     fn get_column_by_name(&self, name: &str) -> Option<PyColumn> {
         self.inner
             .get_column_by_name(name)
@@ -412,6 +691,20 @@ impl PyConstraint {
         })
     }
 
+    #[staticmethod]
+    fn max_scale(scale: u32) -> Self {
+        PyConstraint {
+            inner: Constraint::MaxScale(scale),
+        }
+    }
+
+    #[staticmethod]
+    fn precision(precision: u32, scale: u32) -> Self {
+        PyConstraint {
+            inner: Constraint::Precision { precision, scale },
+        }
+    }
+
     #[staticmethod]
     fn matches_regex(pattern: String) -> Self {
         PyConstraint {
@@ -446,6 +739,205 @@ impl PyConstraint {
             inner: Constraint::LengthBetween { min, max },
         }
     }
+
+    #[staticmethod]
+    fn gt_column(other: String) -> Self {
+        PyConstraint {
+            inner: Constraint::GreaterThanColumn(other),
+        }
+    }
+
+    #[staticmethod]
+    fn ge_column(other: String) -> Self {
+        PyConstraint {
+            inner: Constraint::GreaterThanOrEqualColumn(other),
+        }
+    }
+
+    #[staticmethod]
+    fn lt_column(other: String) -> Self {
+        PyConstraint {
+            inner: Constraint::LessThanColumn(other),
+        }
+    }
+
+    #[staticmethod]
+    fn le_column(other: String) -> Self {
+        PyConstraint {
+            inner: Constraint::LessThanOrEqualColumn(other),
+        }
+    }
+
+    #[staticmethod]
+    fn eq_column(other: String) -> Self {
+        PyConstraint {
+            inner: Constraint::EqualColumn(other),
+        }
+    }
+
+    #[staticmethod]
+    fn unique_with(others: Vec<String>) -> Self {
+        PyConstraint {
+            inner: Constraint::UniqueWith(others),
+        }
+    }
+
+    #[staticmethod]
+    fn exactly_one_not_null(others: Vec<String>) -> Self {
+        PyConstraint {
+            inner: Constraint::ExactlyOneNotNull(others),
+        }
+    }
+
+    #[staticmethod]
+    fn date_between(min: NaiveDate, max: NaiveDate) -> Self {
+        PyConstraint {
+            inner: Constraint::DateBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn not_in_future() -> Self {
+        PyConstraint {
+            inner: Constraint::NotInFuture,
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (max_age_secs, reference=None))]
+    fn freshness(max_age_secs: u64, reference: Option<DateTime<FixedOffset>>) -> Self {
+        PyConstraint {
+            inner: Constraint::Freshness {
+                max_age_secs,
+                reference,
+            },
+        }
+    }
+
+    #[staticmethod]
+    fn is_weekday() -> Self {
+        PyConstraint {
+            inner: Constraint::IsWeekday,
+        }
+    }
+
+    #[staticmethod]
+    fn is_weekend() -> Self {
+        PyConstraint {
+            inner: Constraint::IsWeekend,
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (strict=false))]
+    fn increasing(strict: bool) -> Self {
+        PyConstraint {
+            inner: Constraint::Increasing { strict },
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (strict=false))]
+    fn decreasing(strict: bool) -> Self {
+        PyConstraint {
+            inner: Constraint::Decreasing { strict },
+        }
+    }
+
+    #[staticmethod]
+    fn mean_between(min: f64, max: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::MeanBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn sum_eq(value: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::SumEqual(value),
+        }
+    }
+
+    #[staticmethod]
+    fn std_lt(bound: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::StdBelow(bound),
+        }
+    }
+
+    #[staticmethod]
+    fn median_between(min: f64, max: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::MedianBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn min_between(min: f64, max: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::MinBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn max_between(min: f64, max: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::MaxBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn null_ratio_le(bound: f64) -> Self {
+        PyConstraint {
+            inner: Constraint::NullRatioAtMost(bound),
+        }
+    }
+
+    #[staticmethod]
+    fn distinct_count_between(min: usize, max: usize) -> Self {
+        PyConstraint {
+            inner: Constraint::DistinctCountBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn row_count_between(min: usize, max: usize) -> Self {
+        PyConstraint {
+            inner: Constraint::RowCountBetween { min, max },
+        }
+    }
+
+    #[staticmethod]
+    fn all_of(py: Python<'_>, constraints: Vec<Py<PyConstraint>>) -> Self {
+        PyConstraint {
+            inner: Constraint::All(
+                constraints
+                    .iter()
+                    .map(|c| c.borrow(py).inner.clone())
+                    .collect(),
+            ),
+        }
+    }
+
+    #[staticmethod]
+    fn any_of(py: Python<'_>, constraints: Vec<Py<PyConstraint>>) -> Self {
+        PyConstraint {
+            inner: Constraint::Any(
+                constraints
+                    .iter()
+                    .map(|c| c.borrow(py).inner.clone())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// `not` is a Python keyword, hence the trailing underscore.
+    #[staticmethod]
+    fn not_(py: Python<'_>, constraint: Py<PyConstraint>) -> Self {
+        PyConstraint {
+            inner: Constraint::Not(Box::new(constraint.borrow(py).inner.clone())),
+        }
+    }
 }
 
 #[pyclass(name = "Rule")]
@@ -455,13 +947,56 @@ struct PyRule {
 
 #[pymethods]
 impl PyRule {
+    /// `null_policy` decides rows the check can't decide because of a null:
+    /// `"fail"` (the default), `"pass"` or `"exclude"` (not counted at all).
     #[new]
-    fn new(py: Python<'_>, column: String, constraint: Py<PyConstraint>) -> Self {
+    #[pyo3(signature = (column, constraint, mostly=None, max_failures=None, severity="error", null_policy="fail"))]
+    fn new(
+        py: Python<'_>,
+        column: String,
+        constraint: Py<PyConstraint>,
+        mostly: Option<f64>,
+        max_failures: Option<usize>,
+        severity: &str,
+        null_policy: &str,
+    ) -> PyResult<Self> {
+        let severity = Severity::parse(severity).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("severity must be 'error', 'warning' or 'info'")
+        })?;
+        let null_policy = NullPolicy::parse(null_policy).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "null_policy must be 'fail', 'pass' or 'exclude'",
+            )
+        })?;
+        let mut rule = Rule::new(&column, constraint.borrow(py).inner.clone())
+            .severity(severity)
+            .null_policy(null_policy);
+        match (mostly, max_failures) {
+            (Some(_), Some(_)) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "mostly and max_failures are mutually exclusive",
+                ));
+            }
+            (Some(fraction), None) => {
+                if !(0.0..=1.0).contains(&fraction) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "mostly must be between 0.0 and 1.0",
+                    ));
+                }
+                rule = rule.mostly(fraction);
+            }
+            (None, Some(count)) => rule = rule.max_failures(count),
+            (None, None) => {}
+        }
+        Ok(PyRule { inner: rule })
+    }
+
+    fn when(&self, py: Python<'_>, column: String, constraint: Py<PyConstraint>) -> Self {
         PyRule {
-            inner: Rule {
-                column,
-                constraint: constraint.borrow(py).inner.clone(),
-            },
+            inner: self
+                .inner
+                .clone()
+                .when(&column, constraint.borrow(py).inner.clone()),
         }
     }
 }
@@ -493,43 +1028,319 @@ impl PyValidationResult {
         self.inner.failed_count
     }
 
+    #[getter]
+    fn evaluated_count(&self) -> usize {
+        self.inner.evaluated_count
+    }
+
+    #[getter]
+    fn severity(&self) -> String {
+        self.inner.severity.to_string()
+    }
+
+    #[getter]
+    fn failed_ratio(&self) -> f64 {
+        self.inner.failed_ratio
+    }
+
+    #[getter]
+    fn mostly(&self) -> Option<f64> {
+        match self.inner.tolerance {
+            Some(Tolerance::Mostly(fraction)) => Some(fraction),
+            _ => None,
+        }
+    }
+
+    #[getter]
+    fn max_failures(&self) -> Option<usize> {
+        match self.inner.tolerance {
+            Some(Tolerance::MaxFailures(count)) => Some(count),
+            _ => None,
+        }
+    }
+
     #[getter]
     fn error(&self) -> Option<&str> {
         self.inner.error.as_deref()
     }
 
+    #[getter]
+    fn failed_rows(&self) -> Vec<usize> {
+        self.inner.failed_rows.clone()
+    }
+
+    #[getter]
+    fn sample_values(&self) -> Vec<String> {
+        self.inner.sample_values.clone()
+    }
+
+    #[getter]
+    fn observed(&self) -> Option<f64> {
+        self.inner.observed
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let r = &self.inner;
+        let dict = PyDict::new(py);
+        dict.set_item("column", &r.column)?;
+        dict.set_item("constraint", &r.constraint)?;
+        dict.set_item("passed", r.passed)?;
+        dict.set_item("severity", r.severity.to_string())?;
+        dict.set_item("failed_count", r.failed_count)?;
+        dict.set_item("evaluated_count", r.evaluated_count)?;
+        dict.set_item("failed_ratio", r.failed_ratio)?;
+        dict.set_item("error", r.error.as_deref())?;
+        dict.set_item("failed_rows", &r.failed_rows)?;
+        dict.set_item("sample_values", &r.sample_values)?;
+        dict.set_item("observed", r.observed)?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+fn verdict_str(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Passed => "passed",
+        Verdict::Warning => "warning",
+        Verdict::Failed => "failed",
+    }
+}
+
+#[pyclass(name = "Report")]
+struct PyReport {
+    inner: Report,
+}
+
+impl PyReport {
+    fn wrap(results: &[ValidationResult]) -> Vec<PyValidationResult> {
+        results
+            .iter()
+            .map(|r| PyValidationResult { inner: r.clone() })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl PyReport {
+    fn all_passed(&self) -> bool {
+        self.inner.all_passed()
+    }
+
+    #[getter]
+    fn failures(&self) -> Vec<PyValidationResult> {
+        self.inner
+            .failed()
+            .map(|r| PyValidationResult { inner: r.clone() })
+            .collect()
+    }
+
+    #[getter]
+    fn results(&self) -> Vec<PyValidationResult> {
+        PyReport::wrap(&self.inner.results)
+    }
+
+    /// "passed", "warning" or "failed".
+    #[getter]
+    fn verdict(&self) -> &'static str {
+        verdict_str(self.inner.verdict())
+    }
+
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        self.inner.shape
+    }
+
+    #[getter]
+    fn elapsed(&self) -> f64 {
+        self.inner.elapsed.as_secs_f64()
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let report = &self.inner;
+        let dict = PyDict::new(py);
+        dict.set_item("shape", report.shape)?;
+        dict.set_item("rules", report.rule_count)?;
+        dict.set_item("load_findings", report.load_findings().len())?;
+        dict.set_item("passed", report.passed_count())?;
+        dict.set_item("failed", report.failed_count())?;
+        dict.set_item("warnings", report.warning_count())?;
+        dict.set_item("info", report.info_count())?;
+        dict.set_item("verdict", verdict_str(report.verdict()))?;
+        dict.set_item("elapsed", report.elapsed.as_secs_f64())?;
+
+        let columns = PyList::empty(py);
+        for summary in report.column_summaries() {
+            let item = PyDict::new(py);
+            item.set_item("column", summary.column)?;
+            item.set_item("rules", summary.rules)?;
+            item.set_item("passed", summary.passed)?;
+            item.set_item("failed", summary.failed)?;
+            columns.append(item)?;
+        }
+        dict.set_item("columns", columns)?;
+
+        let results = PyList::empty(py);
+        for result in PyReport::wrap(&report.results) {
+            results.append(result.to_dict(py)?)?;
+        }
+        dict.set_item("results", results)?;
+        Ok(dict)
+    }
+
+    fn to_json(&self) -> String {
+        self.inner.to_json()
+    }
+
+    fn to_junit(&self) -> String {
+        self.inner.to_junit()
+    }
+
+    fn to_markdown(&self) -> String {
+        self.inner.to_markdown()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __getitem__(&self, idx: isize) -> PyResult<PyValidationResult> {
+        let len = self.inner.len() as isize;
+        let idx = if idx < 0 { idx + len } else { idx };
+        if !(0..len).contains(&idx) {
+            return Err(pyo3::exceptions::PyIndexError::new_err(
+                "result index out of range",
+            ));
+        }
+        Ok(PyValidationResult {
+            inner: self.inner[idx as usize].clone(),
+        })
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let results = PyList::new(py, PyReport::wrap(&self.inner.results))?;
+        Ok(results.try_iter()?.into_any())
+    }
+
     fn __repr__(&self) -> String {
         self.inner.to_string()
     }
 }
 
 #[pyfunction]
+#[pyo3(signature = (data, rules, max_failed_rows=100, max_sample_values=10))]
 fn py_validate(
     py: Python<'_>,
     data: Py<PyDataset>,
     rules: Vec<Py<PyRule>>,
-) -> PyResult<Vec<PyValidationResult>> {
+    max_failed_rows: usize,
+    max_sample_values: usize,
+) -> PyResult<PyReport> {
+    let core_rules: Vec<Rule> = rules
+        .into_iter()
+        .map(|v| v.borrow(py).inner.clone())
+        .collect();
+    let options = ValidationOptions {
+        max_failed_rows,
+        max_sample_values,
+    };
+
+    let report = validate_with_options(&data.borrow(py).inner, &core_rules, &options);
+    Ok(PyReport { inner: report })
+}
+
+/// Validates a CSV file `batch_size` rows at a time without loading it whole.
+#[pyfunction]
+#[pyo3(signature = (
+    path,
+    schema,
+    rules,
+    options=None,
+    batch_size=100_000,
+    max_failed_rows=100,
+    max_sample_values=10,
+))]
+#[allow(clippy::too_many_arguments)]
+fn validate_csv(
+    py: Python<'_>,
+    path: &str,
+    schema: &PySchema,
+    rules: Vec<Py<PyRule>>,
+    options: Option<&PyCsvOptions>,
+    batch_size: usize,
+    max_failed_rows: usize,
+    max_sample_values: usize,
+) -> PyResult<PyReport> {
     let core_rules: Vec<Rule> = rules
         .into_iter()
         .map(|v| v.borrow(py).inner.clone())
         .collect();
+    let csv_options = options.map(|o| o.inner.clone()).unwrap_or_default();
+    let options = ValidationOptions {
+        max_failed_rows,
+        max_sample_values,
+    };
+
+    let report = csv_loader::validate_csv(
+        path,
+        &schema.inner,
+        &core_rules,
+        &csv_options,
+        &options,
+        batch_size,
+    )
+    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(PyReport { inner: report })
+}
 
-    let results = validate(&data.borrow(py).inner, &core_rules)
+/// Checks the rules against the schema's column types; returns one message
+/// per rule that can't run.
+#[pyfunction]
+fn check_rules(py: Python<'_>, schema: &PySchema, rules: Vec<Py<PyRule>>) -> Vec<String> {
+    let core_rules: Vec<Rule> = rules
         .into_iter()
-        .map(|r| PyValidationResult { inner: r })
+        .map(|v| v.borrow(py).inner.clone())
+        .collect();
+    core_check_rules(&schema.inner, &core_rules)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[pyfunction]
+fn load_rules(path: &str) -> PyResult<Vec<PyRule>> {
+    let rules = suite::load_rules(path)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(rules.into_iter().map(|inner| PyRule { inner }).collect())
+}
+
+#[pyfunction]
+fn save_rules(py: Python<'_>, path: &str, rules: Vec<Py<PyRule>>) -> PyResult<()> {
+    let core_rules: Vec<Rule> = rules
+        .into_iter()
+        .map(|v| v.borrow(py).inner.clone())
         .collect();
-    Ok(results)
+    suite::save_rules(path, &core_rules)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 }
 
 #[pymodule]
 fn verdict_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDataset>()?;
+    m.add_class::<PyCsvOptions>()?;
     m.add_class::<PyColumn>()?;
     m.add_class::<PyConstraint>()?;
     m.add_class::<PyRule>()?;
     m.add_class::<PyValidationResult>()?;
+    m.add_class::<PyReport>()?;
     m.add_class::<PySchema>()?;
     m.add_class::<PyDataType>()?;
     m.add_function(wrap_pyfunction!(py_validate, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_csv, m)?)?;
+    m.add_function(wrap_pyfunction!(check_rules, m)?)?;
+    m.add_function(wrap_pyfunction!(load_rules, m)?)?;
+    m.add_function(wrap_pyfunction!(save_rules, m)?)?;
     Ok(())
 }
@@ -1,5 +1,11 @@
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+
 import pytest
-from verdict_py import Dataset, Column, Constraint, Rule, Schema, DataType, py_validate
+from verdict_py import (
+    Dataset, Column, Constraint, CsvOptions, Rule, Schema, DataType, py_validate, validate_csv,
+    check_rules, load_rules, save_rules,
+)
 
 
 FIXTURE_CSV = """\
@@ -206,6 +212,11 @@ class TestStringOps:
         col = Column.string(["ann", "clark", "123"])
         assert col.matches_regex("^[a-z]+$") == [True, True, False]
 
+    def test_matches_regex_invalid_pattern(self):
+        col = Column.string(["ann"])
+        with pytest.raises(ValueError):
+            col.matches_regex("[a-z")
+
     def test_str_length(self):
         col = Column.string(["hi", "hello", None])
         assert col.str_length() == [2, 5, None]
@@ -227,6 +238,103 @@ class TestStringOps:
         assert col.contains("an") == [True, None, True]
 
 
+# ── Categorical ops ───────────────────────────────────────────────────────────
+
+class TestCategoricalOps:
+    def test_matches_string_column(self):
+        values = ["DE", "FR", None, "DE", "US"]
+        plain, encoded = Column.string(values), Column.categorical(values)
+        assert repr(encoded) == '[categorical]: ["DE", "FR", null, "DE", "US"]'
+        assert encoded.unique_count() == plain.unique_count() == 4
+        assert encoded.is_in(["DE", "US"]) == plain.is_in(["DE", "US"]) == [
+            True, False, None, True, True,
+        ]
+        assert encoded.starts_with("F") == plain.starts_with("F")
+        assert encoded.matches_regex("^[DU]") == plain.matches_regex("^[DU]")
+        assert encoded.equal("FR") == plain.equal("FR")
+
+    def test_from_csv_categorical(self, csv_path):
+        schema = Schema([("name", DataType.categorical())])
+        ds = Dataset.from_csv(csv_path, schema)
+        col = ds.get_column_by_name("name")
+        assert repr(col) == '[categorical]: ["alice", "bob", null, "diana"]'
+        results = py_validate(ds, [Rule("name", Constraint.is_in(["alice", "bob"]))])
+        assert results[0].failed_rows == [2, 3]
+
+
+# ── Decimal ops ───────────────────────────────────────────────────────────────
+
+class TestDecimalOps:
+    def test_decimal_column(self):
+        col = Column.decimal([Decimal("0.10"), Decimal("0.20"), None, Decimal("1.505")])
+        assert repr(col) == "[decimal]: [0.10, 0.20, null, 1.505]"
+        assert col.sum() == 1.805
+        assert col.scale() == [1, 1, None, 3]
+        assert col.fits_precision(3, 2) == [True, True, None, False]
+        assert col.equal(0.1) == [True, False, None, False]
+
+    def test_decimal_constraints(self):
+        ds = Dataset(
+            headers=["total"],
+            columns=[Column.decimal([Decimal("0.30"), Decimal("12.345"), Decimal("100")])],
+        )
+        results = py_validate(ds, [
+            Rule("total", Constraint.eq(0.3)),
+            Rule("total", Constraint.max_scale(2)),
+            Rule("total", Constraint.precision(4, 2)),
+        ])
+        assert [r.failed_rows for r in results] == [[1, 2], [1], [1, 2]]
+
+    def test_from_csv_decimal(self, tmp_path):
+        csv = tmp_path / "ledger.csv"
+        csv.write_text("amount\n0.10\n0.20\n")
+        ds = Dataset.from_csv(str(csv), Schema([("amount", DataType.decimal())]))
+        assert repr(ds.get_column_by_name("amount")) == "[decimal]: [0.10, 0.20]"
+
+
+# ── Date ops ──────────────────────────────────────────────────────────────────
+
+class TestDateOps:
+    def test_date_column(self):
+        col = Column.date([date(2024, 1, 5), date(2024, 1, 6), None])
+        assert col.year() == [2024, 2024, None]
+        assert col.day() == [5, 6, None]
+        assert col.is_weekend() == [False, True, None]
+        assert repr(col) == "[date]: [2024-01-05, 2024-01-06, null]"
+
+    def test_datetime_column(self):
+        tz = timezone(timedelta(hours=2))
+        col = Column.datetime([datetime(2024, 1, 31, 23, 0, tzinfo=tz), None])
+        assert col.month() == [1, None]
+        assert repr(col) == "[datetime]: [2024-01-31T23:00:00+02:00, null]"
+
+    def test_date_constraints(self):
+        ds = Dataset(
+            headers=["day", "at"],
+            columns=[
+                Column.date([date(2024, 1, 5), date(2024, 1, 6), date(2024, 1, 4)]),
+                Column.datetime([
+                    datetime(2024, 1, 5, 12, tzinfo=timezone.utc),
+                    datetime(2024, 1, 6, 12, tzinfo=timezone.utc),
+                    datetime(2999, 1, 1, tzinfo=timezone.utc),
+                ]),
+            ],
+        )
+        reference = datetime(2024, 1, 7, tzinfo=timezone.utc)
+        results = py_validate(ds, [
+            Rule("day", Constraint.date_between(date(2024, 1, 5), date(2024, 1, 31))),
+            Rule("at", Constraint.not_in_future()),
+            Rule("at", Constraint.freshness(86400, reference=reference)),
+            Rule("day", Constraint.is_weekday()),
+            Rule("day", Constraint.is_weekend()),
+            Rule("day", Constraint.increasing()),
+            Rule("at", Constraint.increasing(strict=True)),
+        ])
+        assert [r.failed_rows for r in results] == [
+            [2], [2], [0], [1], [0, 2], [2], [],
+        ]
+
+
 # ── Dataset ───────────────────────────────────────────────────────────────────
 
 class TestDataset:
@@ -310,11 +418,252 @@ class TestValidation:
         results = py_validate(dataset, rules)
         assert len(results) == 13
 
+    def test_invalid_regex_fails_only_that_rule(self, dataset):
+        rules = [
+            Rule("name", Constraint.matches_regex("[a-z")),
+            Rule("name", Constraint.not_null()),
+        ]
+        results = py_validate(dataset, rules)
+        assert not results[0].is_passed
+        assert "Invalid regex '[a-z' in rules[0]" in results[0].error
+        assert results[1].error is None
+
+    def test_check_rules(self):
+        schema = Schema([("id", DataType.integer()), ("name", DataType.string())])
+        rules = [
+            Rule("id", Constraint.gt(0.0)),
+            Rule("name", Constraint.gt(0.0)),
+        ]
+        errors = check_rules(schema, rules)
+        assert len(errors) == 1
+        assert errors[0].startswith("Incompatible type in rules[1] (column 'name'): > 0 requires")
+
     def test_with_nulls_column(self, dataset):
         results = py_validate(dataset, [Rule("id_with_nulls", Constraint.not_null())])
         assert not results[0].is_passed
         assert results[0].failed_count == 2
 
+    def test_failed_rows_and_samples(self, dataset):
+        results = py_validate(dataset, [Rule("id", Constraint.gt(2.0))])
+        assert results[0].failed_rows == [0, 1]
+        assert results[0].sample_values == ["1", "2"]
+
+    def test_column_comparison(self, dataset):
+        results = py_validate(dataset, [Rule("age", Constraint.gt_column("score"))])
+        assert results[0].column == "age, score"
+        assert results[0].failed_rows == [0, 1, 3]
+
+    def test_unique_with(self, dataset):
+        results = py_validate(dataset, [Rule("active", Constraint.unique_with(["id"]))])
+        assert results[0].is_passed
+
+    def test_exactly_one_not_null(self, dataset):
+        rule = Rule("id_with_nulls", Constraint.exactly_one_not_null(["score_with_nulls"]))
+        results = py_validate(dataset, [rule])
+        assert results[0].is_passed
+
+    def test_conditional_rule(self, dataset):
+        rule = Rule("age", Constraint.not_null()).when("active", Constraint.eq(1.0))
+        results = py_validate(dataset, [rule])
+        assert results[0].evaluated_count == 0
+
+        rule = Rule("age", Constraint.not_null()).when("id", Constraint.ge(2.0))
+        results = py_validate(dataset, [rule])
+        assert results[0].evaluated_count == 3
+        assert results[0].failed_rows == [1]
+
+    def test_combinators(self, dataset):
+        rule = Rule(
+            "age",
+            Constraint.any_of([Constraint.not_(Constraint.not_null()), Constraint.between(0.0, 40.0)]),
+        )
+        results = py_validate(dataset, [rule])
+        assert results[0].is_passed
+        assert results[0].constraint == "null or between 0 and 40"
+
+        rule = Rule(
+            "name",
+            Constraint.all_of(
+                [Constraint.not_(Constraint.is_in(["lex"])), Constraint.length_between(3, 5)]
+            ),
+        )
+        results = py_validate(dataset, [rule])
+        assert results[0].failed_rows == [3]
+        assert results[0].constraint == "not in {'lex'} and length between 3 and 5"
+
+    def test_mostly(self, dataset):
+        results = py_validate(dataset, [Rule("age", Constraint.not_null(), mostly=0.75)])
+        assert results[0].is_passed
+        assert results[0].failed_count == 1
+        assert results[0].failed_ratio == 0.25
+        assert results[0].mostly == 0.75
+
+        results = py_validate(dataset, [Rule("age", Constraint.not_null(), mostly=0.9)])
+        assert not results[0].is_passed
+
+    def test_max_failures(self, dataset):
+        results = py_validate(dataset, [Rule("id", Constraint.gt(2.0), max_failures=2)])
+        assert results[0].is_passed
+        assert results[0].max_failures == 2
+
+    def test_severity(self, dataset):
+        rule = Rule("age", Constraint.not_null(), severity="warning")
+        results = py_validate(dataset, [rule])
+        assert results[0].severity == "warning"
+        assert results.verdict == "warning"
+
+        results = py_validate(dataset, [rule, Rule("age", Constraint.not_null())])
+        assert results.verdict == "failed"
+
+        results = py_validate(dataset, [Rule("id", Constraint.not_null())])
+        assert results.verdict == "passed"
+
+    def test_invalid_severity(self):
+        with pytest.raises(ValueError):
+            Rule("id", Constraint.not_null(), severity="fatal")
+
+    def test_aggregates(self, dataset):
+        rules = [
+            Rule("age", Constraint.mean_between(30.0, 40.0)),
+            Rule("age", Constraint.null_ratio_le(0.25)),
+            Rule("name", Constraint.distinct_count_between(1, 3)),
+            Rule("id", Constraint.row_count_between(1, 10)),
+        ]
+        results = py_validate(dataset, rules)
+        assert [r.observed for r in results] == [30.0, 0.25, 4.0, 4.0]
+        assert [r.is_passed for r in results] == [True, True, False, True]
+        assert results[2].error == "distinct count not between 1 and 3"
+        assert results[2].to_dict()["observed"] == 4.0
+
+    def test_null_policy(self, dataset):
+        rule = Rule("age", Constraint.gt(25.0))
+        assert py_validate(dataset, [rule])[0].failed_rows == [0, 1]
+
+        results = py_validate(dataset, [Rule("age", Constraint.gt(25.0), null_policy="pass")])
+        assert results[0].failed_rows == [0]
+        assert results[0].evaluated_count == 4
+
+        results = py_validate(dataset, [Rule("age", Constraint.gt(25.0), null_policy="exclude")])
+        assert results[0].failed_rows == [0]
+        assert results[0].evaluated_count == 3
+
+        with pytest.raises(ValueError):
+            Rule("age", Constraint.gt(25.0), null_policy="ignore")
+
+    def test_invalid_tolerance(self):
+        with pytest.raises(ValueError):
+            Rule("id", Constraint.not_null(), mostly=1.5)
+        with pytest.raises(ValueError):
+            Rule("id", Constraint.not_null(), mostly=0.9, max_failures=1)
+
+    def test_failed_rows_cap(self, dataset):
+        results = py_validate(
+            dataset, [Rule("id", Constraint.gt(2.0))], max_failed_rows=1, max_sample_values=0
+        )
+        assert results[0].failed_count == 2
+        assert results[0].failed_rows == [0]
+        assert results[0].sample_values == []
+
+
+class TestReport:
+    def test_report_summary(self, dataset):
+        rules = [
+            Rule("id", Constraint.not_null()),
+            Rule("age", Constraint.not_null()),
+            Rule("age", Constraint.gt(0.0), severity="warning"),
+        ]
+        report = py_validate(dataset, rules)
+        assert not report.all_passed()
+        assert len(report.failures) == 2
+        assert report.shape == (4, 7)
+        assert report.verdict == "failed"
+        assert report[-1].severity == "warning"
+        with pytest.raises(IndexError):
+            report[3]
+
+    def test_to_dict(self, dataset):
+        report = py_validate(dataset, [Rule("id", Constraint.not_null())])
+        d = report.to_dict()
+        assert d["rules"] == 1
+        assert d["passed"] == 1
+        assert d["verdict"] == "passed"
+        assert d["columns"] == [{"column": "id", "rules": 1, "passed": 1, "failed": 0}]
+        assert d["results"][0]["column"] == "id"
+        assert report.all_passed()
+
+    def test_to_json(self, dataset):
+        import json
+
+        report = py_validate(dataset, [Rule("age", Constraint.not_null())])
+        doc = json.loads(report.to_json())
+        assert doc["schema_version"] == 1
+        assert doc["verdict"] == "failed"
+        assert doc["summary"]["failed"] == 1
+        assert doc["results"][0]["status"] == "fail"
+        assert doc["results"][0]["failed_rows"] == [1]
+
+    def test_to_junit(self, dataset):
+        import xml.etree.ElementTree as ET
+
+        rules = [Rule("id", Constraint.not_null()), Rule("age", Constraint.not_null())]
+        root = ET.fromstring(py_validate(dataset, rules).to_junit())
+        suite = root.find("testsuite")
+        assert suite.get("tests") == "2"
+        assert suite.get("failures") == "1"
+        cases = suite.findall("testcase")
+        assert cases[0].find("failure") is None
+        assert cases[1].get("classname") == "age"
+        assert cases[1].find("failure") is not None
+
+    def test_to_markdown(self, dataset):
+        md = py_validate(dataset, [Rule("age", Constraint.not_null())]).to_markdown()
+        assert md.startswith("### Verdict: FAILED")
+        assert "| FAIL | `age` | `not null` | 1/4 |" in md
+
+
+# ── Rule suites ───────────────────────────────────────────────────────────────
+
+SUITE_YAML = """\
+rules:
+  - column: id
+    constraint: unique
+  - column: id
+    constraint: { greater_than: 1 }
+    severity: warning
+"""
+
+
+class TestRuleSuites:
+    def test_load_yaml(self, dataset, tmp_path):
+        path = tmp_path / "rules.yaml"
+        path.write_text(SUITE_YAML)
+        rules = load_rules(str(path))
+        assert len(rules) == 2
+        report = py_validate(dataset, rules)
+        assert report.verdict == "warning"
+        assert report[1].failed_rows == [0]
+
+    def test_round_trip(self, dataset, tmp_path):
+        rules = [
+            Rule("id", Constraint.not_null()),
+            Rule("score", Constraint.lt(10.0), mostly=0.5),
+        ]
+        for ext in ("json", "yaml", "toml"):
+            path = tmp_path / f"rules.{ext}"
+            save_rules(str(path), rules)
+            loaded = load_rules(str(path))
+            assert [r.is_passed for r in py_validate(dataset, loaded)] == [True, True]
+
+    def test_invalid_rule(self, tmp_path):
+        path = tmp_path / "rules.json"
+        path.write_text('[{"column": "id", "constraint": "not_nul"}]')
+        with pytest.raises(ValueError, match=r"rules\[0\] \(column 'id'\)"):
+            load_rules(str(path))
+
+    def test_unsupported_extension(self, tmp_path):
+        with pytest.raises(ValueError):
+            load_rules(str(tmp_path / "rules.txt"))
+
 
 # ── CSV loading ───────────────────────────────────────────────────────────────
 
@@ -340,6 +689,124 @@ class TestCsvLoading:
         with pytest.raises(ValueError):
             Dataset.from_csv(str(csv), schema)
 
+    def test_from_csv_by_header_name(self, csv_path):
+        schema = Schema([("age", DataType.integer()), ("id", DataType.integer())])
+        ds = Dataset.from_csv(csv_path, schema)
+        assert ds.shape() == (4, 2)
+        assert ds.get_column_index("age") == 0
+        assert ds.get_column_by_name("name") is None
+
+    def test_from_csv_keep_extra_columns(self, csv_path):
+        schema = Schema([("id", DataType.integer())], extra_columns="keep")
+        ds = Dataset.from_csv(csv_path, schema)
+        assert ds.shape() == (4, 5)
+        assert ds.get_column_by_name("name").null_count() == 1
+
+    def test_from_csv_missing_column(self, csv_path):
+        schema = Schema([("id", DataType.integer()), ("email", DataType.string())])
+        with pytest.raises(ValueError, match="Missing required columns: email"):
+            Dataset.from_csv(csv_path, schema)
+
+    def test_from_csv_ordered(self, csv_path):
+        schema = Schema([("name", DataType.string())], ordered=True)
+        with pytest.raises(ValueError, match="Expected column 'name' at position 0"):
+            Dataset.from_csv(csv_path, schema)
+        with pytest.raises(ValueError):
+            Schema([("id", DataType.integer())], extra_columns="drop")
+
+    def test_from_csv_with_options(self, tmp_path):
+        csv = tmp_path / "vendor.csv"
+        csv.write_text("# export\nid;name;score;ok\n1; alice ;9.5;Y\n2;NA;-;N\n")
+        options = CsvOptions(
+            delimiter=";",
+            comment="#",
+            trim=True,
+            null_values=["", "NA"],
+            column_null_values={"score": ["-"]},
+            true_values=["Y"],
+            false_values=["N"],
+        )
+        schema = Schema([
+            ("id", DataType.integer()),
+            ("name", DataType.string()),
+            ("score", DataType.float()),
+            ("ok", DataType.boolean()),
+        ])
+        ds = Dataset.from_csv_with_options(str(csv), schema, options)
+        assert ds.shape() == (2, 4)
+        assert ds.get_column_by_name("name").null_count() == 1
+        assert ds.get_column_by_name("score").null_count() == 1
+        assert ds.get_column_by_name("ok").null_count() == 0
+
+    def test_from_csv_without_headers(self, tmp_path):
+        csv = tmp_path / "data.tsv"
+        csv.write_text("1\tx\n2\ty\n")
+        options = CsvOptions(delimiter="\t", has_headers=False)
+        ds = Dataset.from_csv_with_options(
+            str(csv), Schema([("id", DataType.integer())], extra_columns="keep"), options
+        )
+        assert ds.shape() == (2, 2)
+        assert ds.get_column_index("column_2") == 1
+
+    def test_from_csv_lenient(self, tmp_path):
+        csv = tmp_path / "dirty.csv"
+        csv.write_text("id,score\n1,9.5\ntwo,8.0\n3\n4,1.0,x,y\n")
+        schema = Schema([("id", DataType.integer()), ("score", DataType.float())])
+        with pytest.raises(ValueError):
+            Dataset.from_csv(str(csv), schema)
+
+        ds = Dataset.from_csv_with_options(str(csv), schema, CsvOptions(lenient=True))
+        assert ds.shape() == (4, 2)
+        assert ds.coercions == [
+            {"row": 1, "column": "id", "value": "two", "expected": "Int"},
+            {"row": 2, "column": "score", "value": None, "expected": "Float"},
+        ]
+        assert ds.overlong_rows == [{"row": 3, "extra": ["x", "y"]}]
+
+        report = py_validate(ds, [Rule("id", Constraint.not_null())])
+        assert len(report) == 4
+        assert report.to_dict()["rules"] == 1
+        assert report.to_dict()["load_findings"] == 3
+        assert report[1].constraint == "parses as Int"
+        assert report[1].failed_rows == [1]
+        assert report[2].sample_values == ["<missing>"]
+        assert report[3].constraint == "row length = header length"
+        assert report[3].sample_values == ["(x, y)"]
+
+    def test_validate_csv_in_batches(self, tmp_path):
+        csv = tmp_path / "orders.csv"
+        csv.write_text("id,amount\n1,5.0\n2,-1.0\n1,3.0\n3,\n2,4.0\n")
+        schema = Schema([("id", DataType.integer()), ("amount", DataType.float())])
+        rules = [
+            Rule("id", Constraint.unique()),
+            Rule("amount", Constraint.gt(0.0)),
+        ]
+        expected = py_validate(Dataset.from_csv(str(csv), schema), rules)
+
+        report = validate_csv(str(csv), schema, rules, batch_size=2)
+        assert report.shape == expected.shape
+        assert report[0].failed_rows == expected[0].failed_rows == [2, 4]
+        assert report[1].failed_rows == expected[1].failed_rows == [1, 3]
+
+    def test_csv_options_invalid_delimiter(self):
+        with pytest.raises(ValueError):
+            CsvOptions(delimiter=";;")
+
+    def test_from_csv_infer(self, csv_path):
+        ds = Dataset.from_csv_infer(csv_path)
+        assert ds.shape() == (4, 5)
+        assert ds.get_column_by_name("id").sum() == 10
+        assert ds.get_column_by_name("score").sum() == 31.0
+        assert ds.get_column_by_name("name").sum() is None
+        assert ds.get_column_by_name("age").null_count() == 1
+
+    def test_from_csv_infer_sample_rows(self, tmp_path):
+        csv = tmp_path / "mixed.csv"
+        csv.write_text("code\n1\n2\nx\n")
+        assert Dataset.from_csv_infer(str(csv)).get_column_by_name("code").sum() is None
+        with pytest.raises(ValueError):
+            Dataset.from_csv_infer(str(csv), sample_rows=2)
+
     def test_from_csv_and_validate(self, csv_path):
         schema = Schema([
             ("id", DataType.integer()),
@@ -356,3 +823,20 @@ class TestCsvLoading:
         ]
         results = py_validate(ds, rules)
         assert all(r.is_passed for r in results)
+
+
+    def test_from_csv_dates(self, tmp_path):
+        csv = tmp_path / "events.csv"
+        csv.write_text("day,at\n31/01/2024,2024-01-31 09:30:00\n01/02/2024,2024-02-01T08:00:00Z\n")
+        schema = Schema([("day", DataType.date()), ("at", DataType.datetime())])
+        with pytest.raises(ValueError):
+            Dataset.from_csv(str(csv), schema)
+
+        options = CsvOptions(date_formats=["%d/%m/%Y"], timezone="+02:00")
+        ds = Dataset.from_csv_with_options(str(csv), schema, options)
+        assert ds.get_column_by_name("day").month() == [1, 2]
+        assert repr(ds.get_column_by_name("at")) == (
+            "[datetime]: [2024-01-31T09:30:00+02:00, 2024-02-01T08:00:00+00:00]"
+        )
+        with pytest.raises(ValueError):
+            CsvOptions(timezone="Europe/Paris")